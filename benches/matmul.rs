@@ -0,0 +1,43 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use matrixes::Matrix;
+use std::hint::black_box;
+use std::iter::zip;
+
+/// The previous kernel, one dot product of a row and a column per output element
+fn naive<const N: usize>(lhs: &Matrix<f64, N, N>, rhs: &Matrix<f64, N, N>) -> Matrix<f64, N, N> {
+    Matrix::from_fn(|i, j| {
+        zip(lhs.get_row(i).expect(""), rhs.get_col(j).expect(""))
+            .fold(0.0, |acc, (lhs, rhs)| acc + lhs * rhs)
+    })
+}
+
+fn operands<const N: usize>() -> (Matrix<f64, N, N>, Matrix<f64, N, N>) {
+    let value =
+        |row: usize, col: usize| f64::from(u32::try_from((row * 7 + col * 13) % 17).expect(""));
+
+    (
+        Matrix::from_fn(value),
+        Matrix::from_fn(|row, col| value(col, row) - 8.0),
+    )
+}
+
+fn bench_size<const N: usize>(c: &mut Criterion) {
+    let (lhs, rhs) = operands::<N>();
+    let mut group = c.benchmark_group("matmul");
+
+    group.bench_function(BenchmarkId::new("naive", N), |b| {
+        b.iter(|| naive(black_box(&lhs), black_box(&rhs)));
+    });
+    group.bench_function(BenchmarkId::new("blocked", N), |b| {
+        b.iter(|| black_box(&lhs) * black_box(&rhs));
+    });
+    group.finish();
+}
+
+fn matmul(c: &mut Criterion) {
+    bench_size::<64>(c);
+    bench_size::<256>(c);
+}
+
+criterion_group!(benches, matmul);
+criterion_main!(benches);
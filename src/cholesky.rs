@@ -0,0 +1,357 @@
+use crate::{
+    errors::{CholeskyError, SolveError},
+    Matrix,
+};
+use num_traits::{Float, NumAssign};
+use std::array;
+
+/// A Cholesky factorisation of a symmetric positive definite matrix, `A = LLᵀ`
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Cholesky<T, const N: usize> {
+    l: Matrix<T, N, N>,
+}
+
+impl<T, const N: usize> Cholesky<T, N>
+where
+    T: Float + NumAssign,
+{
+    /// Returns the lower triangular factor `L`
+    pub const fn l(&self) -> &Matrix<T, N, N> {
+        &self.l
+    }
+
+    /// Returns the solution `x` of `Ax = b` for every column of `b`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[4.0, 2.0], [2.0, 5.0]]);
+    /// let cholesky = a.cholesky().expect("a is positive definite");
+    ///
+    /// assert_eq!(cholesky.solve(&Matrix::from([[6.0], [7.0]])), Matrix::from([[1.0], [1.0]]));
+    /// ```
+    #[must_use]
+    pub fn solve<const K: usize>(&self, b: &Matrix<T, N, K>) -> Matrix<T, N, K> {
+        let mut x = *b;
+
+        for col in 0..K {
+            for row in 0..N {
+                for k in 0..row {
+                    let elem = x[(k, col)];
+                    x[(row, col)] -= self.l[(row, k)] * elem;
+                }
+
+                x[(row, col)] /= self.l[(row, row)];
+            }
+
+            for row in (0..N).rev() {
+                for k in row + 1..N {
+                    let elem = x[(k, col)];
+                    x[(row, col)] -= self.l[(k, row)] * elem;
+                }
+
+                x[(row, col)] /= self.l[(row, row)];
+            }
+        }
+
+        x
+    }
+}
+
+/// An `LDLᵀ` factorisation of a symmetric matrix with symmetric pivoting, `PAPᵀ = LDLᵀ`
+///
+/// `L` is unit lower triangular, `D` is block diagonal with `1×1` and `2×2` blocks and `P` is a
+/// permutation. Unlike [`Cholesky`] the matrix may be indefinite or singular.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Ldlt<T, const N: usize> {
+    l: Matrix<T, N, N>,
+    d: Matrix<T, N, N>,
+    permutation: [usize; N],
+}
+
+impl<T, const N: usize> Ldlt<T, N>
+where
+    T: Float + NumAssign,
+{
+    /// Returns the unit lower triangular factor `L`
+    pub const fn l(&self) -> &Matrix<T, N, N> {
+        &self.l
+    }
+
+    /// Returns the block diagonal factor `D`
+    pub const fn d(&self) -> &Matrix<T, N, N> {
+        &self.d
+    }
+
+    /// Returns the symmetric permutation `P` as a permutation matrix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[0.0, 1.0, 2.0], [1.0, 0.0, 3.0], [2.0, 3.0, 0.0]]);
+    /// let ldlt = a.ldlt();
+    /// let (l, p) = (ldlt.l(), ldlt.p());
+    /// let error = p * a * p.to_transpose() - l * ldlt.d() * l.to_transpose();
+    ///
+    /// assert!(error.iter().all(|elem: &f64| elem.abs() < 1e-12));
+    /// ```
+    pub fn p(&self) -> Matrix<T, N, N> {
+        Matrix::from_fn(|row, col| {
+            if self.permutation[row] == col {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Returns the solution `x` of `Ax = b` for every column of `b` or an error
+    ///
+    /// # Errors
+    /// The factorised matrix must not be singular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[1.0, 2.0], [2.0, 1.0]]);
+    ///
+    /// assert_eq!(a.ldlt().solve(&Matrix::from([[3.0], [3.0]])), Ok(Matrix::from([[1.0], [1.0]])));
+    /// ```
+    pub fn solve<const K: usize>(
+        &self,
+        b: &Matrix<T, N, K>,
+    ) -> Result<Matrix<T, N, K>, SolveError> {
+        let mut x = Matrix::from_fn(|row, col| b[(self.permutation[row], col)]);
+
+        for col in 0..K {
+            for row in 0..N {
+                for k in 0..row {
+                    let elem = x[(k, col)];
+                    x[(row, col)] -= self.l[(row, k)] * elem;
+                }
+            }
+
+            let mut row = 0;
+
+            while row < N {
+                if row + 1 < N && !self.d[(row + 1, row)].is_zero() {
+                    let off = self.d[(row + 1, row)];
+                    let ratio0 = self.d[(row + 1, row + 1)] / off;
+                    let ratio1 = self.d[(row, row)] / off;
+                    let scaled_det = ratio0 * ratio1 - T::one();
+
+                    if scaled_det.is_zero() {
+                        return Err(SolveError::Singular { pivot_col: row });
+                    }
+
+                    let t = scaled_det.recip() / off;
+                    let (y0, y1) = (x[(row, col)], x[(row + 1, col)]);
+                    x[(row, col)] = t * (ratio0 * y0 - y1);
+                    x[(row + 1, col)] = t * (ratio1 * y1 - y0);
+                    row += 2;
+                } else {
+                    if self.d[(row, row)].is_zero() {
+                        return Err(SolveError::Singular { pivot_col: row });
+                    }
+
+                    x[(row, col)] /= self.d[(row, row)];
+                    row += 1;
+                }
+            }
+
+            for row in (0..N).rev() {
+                for k in row + 1..N {
+                    let elem = x[(k, col)];
+                    x[(row, col)] -= self.l[(k, row)] * elem;
+                }
+            }
+        }
+
+        let mut unpermuted = x;
+
+        for (row, &original) in self.permutation.iter().enumerate() {
+            for col in 0..K {
+                unpermuted[(original, col)] = x[(row, col)];
+            }
+        }
+
+        Ok(unpermuted)
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + NumAssign,
+{
+    /// Returns the Cholesky factorisation of a symmetric positive definite matrix or an error
+    ///
+    /// Only the lower triangle of the matrix is read.
+    ///
+    /// # Errors
+    /// The matrix must be positive definite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::{errors::CholeskyError, Matrix};
+    ///
+    /// let a = Matrix::from([[4.0, 2.0], [2.0, 5.0]]);
+    /// let indefinite = Matrix::from([[1.0, 2.0], [2.0, 1.0]]);
+    ///
+    /// assert_eq!(a.cholesky().map(|c| *c.l()), Ok(Matrix::from([[2.0, 0.0], [1.0, 2.0]])));
+    /// assert_eq!(indefinite.cholesky(), Err(CholeskyError::NotPositiveDefinite { pivot: 1 }));
+    /// ```
+    pub fn cholesky(&self) -> Result<Cholesky<T, N>, CholeskyError> {
+        let tolerances = self.lower_symmetric().pivot_tolerances();
+        let mut l = Self::of_elem(T::zero());
+
+        for col in 0..N {
+            let pivot = (0..col).fold(self[(col, col)], |acc, k| acc - l[(col, k)] * l[(col, k)]);
+
+            if pivot <= tolerances[col] || pivot.is_nan() {
+                return Err(CholeskyError::NotPositiveDefinite { pivot: col });
+            }
+
+            l[(col, col)] = pivot.sqrt();
+
+            for row in col + 1..N {
+                let elem =
+                    (0..col).fold(self[(row, col)], |acc, k| acc - l[(row, k)] * l[(col, k)]);
+                l[(row, col)] = elem / l[(col, col)];
+            }
+        }
+
+        Ok(Cholesky { l })
+    }
+
+    /// Returns the `LDLᵀ` factorisation of a symmetric matrix with Bunch–Kaufman pivoting
+    ///
+    /// Only the lower triangle of the matrix is read. Every symmetric matrix has a factorisation:
+    /// where a diagonal pivot would be too small relative to its column, rows and columns are
+    /// swapped symmetrically and a `2×2` pivot block may be used instead, keeping the elements of
+    /// `L` bounded. A column that is already negligible gets a zero pivot, as happens for
+    /// singular matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[4.0, 2.0], [2.0, -1.0]]);
+    /// let ldlt = a.ldlt();
+    ///
+    /// assert_eq!(*ldlt.l(), Matrix::from([[1.0, 0.0], [0.5, 1.0]]));
+    /// assert_eq!(*ldlt.d(), Matrix::from([[4.0, 0.0], [0.0, -2.0]]));
+    ///
+    /// let zero_diagonal = Matrix::from([[0.0, 1.0], [1.0, 0.0]]);
+    ///
+    /// assert_eq!(*zero_diagonal.ldlt().d(), zero_diagonal);
+    /// ```
+    #[must_use]
+    #[allow(clippy::many_single_char_names)]
+    pub fn ldlt(&self) -> Ldlt<T, N> {
+        // (1 + √17) / 8 balances the growth of 1×1 and 2×2 pivot steps
+        let alpha = (T::one() + T::from(17).unwrap_or_else(T::one).sqrt())
+            / T::from(8).unwrap_or_else(T::one);
+        let mut a = self.lower_symmetric();
+        let tolerances = a.pivot_tolerances();
+        let mut l = Self::identity();
+        let mut d = Self::of_elem(T::zero());
+        let mut permutation: [usize; N] = array::from_fn(|row| row);
+        let mut k = 0;
+
+        while k < N {
+            let diagonal = a[(k, k)].abs();
+            let (max_row, col_max) = (k + 1..N).fold((k, T::zero()), |(max_row, max), row| {
+                if a[(row, k)].abs() > max {
+                    (row, a[(row, k)].abs())
+                } else {
+                    (max_row, max)
+                }
+            });
+
+            if diagonal.max(col_max) <= tolerances[permutation[k]] {
+                k += 1;
+                continue;
+            }
+
+            let (swap, size) = if diagonal >= alpha * col_max {
+                (k, 1)
+            } else {
+                let row_max = (k..N)
+                    .filter(|&col| col != max_row)
+                    .fold(T::zero(), |max, col| max.max(a[(max_row, col)].abs()));
+
+                if diagonal >= alpha * col_max * (col_max / row_max) {
+                    (k, 1)
+                } else if a[(max_row, max_row)].abs() >= alpha * row_max {
+                    (max_row, 1)
+                } else {
+                    (max_row, 2)
+                }
+            };
+
+            let target = k + size - 1;
+            a.swap_rows(target, swap).unwrap_or_else(|_| unreachable!());
+            a.swap_cols(target, swap).unwrap_or_else(|_| unreachable!());
+            permutation.swap(target, swap);
+            for col in 0..k {
+                l.swap_elems((target, col), (swap, col))
+                    .unwrap_or_else(|_| unreachable!());
+            }
+
+            if size == 1 {
+                let pivot = a[(k, k)];
+                d[(k, k)] = pivot;
+
+                for row in k + 1..N {
+                    l[(row, k)] = a[(row, k)] / pivot;
+                }
+                for row in k + 1..N {
+                    for col in k + 1..N {
+                        let update = l[(row, k)] * a[(col, k)];
+                        a[(row, col)] -= update;
+                    }
+                }
+            } else {
+                // The inverse of the 2×2 pivot is formed relative to its off-diagonal element
+                // rather than through its determinant, which could overflow or underflow
+                let b = a[(k + 1, k)];
+                let (ratio0, ratio1) = (a[(k + 1, k + 1)] / b, a[(k, k)] / b);
+                let t = (ratio0 * ratio1 - T::one()).recip() / b;
+
+                d[(k, k)] = a[(k, k)];
+                d[(k + 1, k)] = b;
+                d[(k, k + 1)] = b;
+                d[(k + 1, k + 1)] = a[(k + 1, k + 1)];
+
+                for row in k + 2..N {
+                    let (x0, x1) = (a[(row, k)], a[(row, k + 1)]);
+                    l[(row, k)] = t * (ratio0 * x0 - x1);
+                    l[(row, k + 1)] = t * (ratio1 * x1 - x0);
+                }
+                for row in k + 2..N {
+                    for col in k + 2..N {
+                        let update = l[(row, k)] * a[(col, k)] + l[(row, k + 1)] * a[(col, k + 1)];
+                        a[(row, col)] -= update;
+                    }
+                }
+            }
+
+            k += size;
+        }
+
+        Ldlt { l, d, permutation }
+    }
+
+    /// Returns the symmetric matrix with the same lower triangle
+    fn lower_symmetric(&self) -> Self {
+        Self::from_fn(|row, col| self[(row.max(col), row.min(col))])
+    }
+}
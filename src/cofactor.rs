@@ -0,0 +1,212 @@
+use crate::{errors::IndexError, Matrix};
+use num_traits::Signed;
+use std::array;
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Signed + Copy,
+{
+    /// Returns the minor `Mᵢⱼ`, the determinant of the matrix without row `i` and column `j`
+    ///
+    /// Computed by Bareiss elimination with partial pivoting on the submatrix. Every intermediate
+    /// value is itself a minor of the submatrix and each division is exact, so integer matrices
+    /// give exact results as long as those minors do not overflow.
+    ///
+    /// # Errors
+    /// - i must index within bounds
+    /// - j must index within bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let m = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 10]]);
+    ///
+    /// assert_eq!(m.minor(0, 0), Ok(2));
+    /// assert_eq!(m.minor(1, 2), Ok(-6));
+    /// ```
+    pub fn minor(&self, i: usize, j: usize) -> Result<T, IndexError>
+    where
+        T: PartialOrd,
+    {
+        self.get(i, j)?;
+
+        Ok(self.submatrix_determinant(i, j))
+    }
+
+    /// Returns the cofactor `Cᵢⱼ = (-1)ⁱ⁺ʲMᵢⱼ`
+    ///
+    /// # Errors
+    /// - i must index within bounds
+    /// - j must index within bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::{errors::IndexError, Matrix};
+    ///
+    /// let m = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 10]]);
+    ///
+    /// assert_eq!(m.cofactor(1, 2), Ok(6));
+    /// assert_eq!(m.cofactor(3, 0), Err(IndexError::Row(3)));
+    /// ```
+    pub fn cofactor(&self, i: usize, j: usize) -> Result<T, IndexError>
+    where
+        T: PartialOrd,
+    {
+        let minor = self.minor(i, j)?;
+
+        Ok(if (i + j).is_multiple_of(2) {
+            minor
+        } else {
+            -minor
+        })
+    }
+
+    /// Returns the matrix of cofactors, the transpose of the adjugate
+    ///
+    /// Each cofactor is computed from its own minor, see [`Matrix::minor`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let m = Matrix::from([[2, 0], [1, 3]]);
+    ///
+    /// assert_eq!(m.cofactor_matrix(), Matrix::from([[3, -1], [0, 2]]));
+    /// ```
+    #[must_use]
+    pub fn cofactor_matrix(&self) -> Self
+    where
+        T: PartialOrd,
+    {
+        Self::from_fn(|i, j| self.cofactor(i, j).unwrap_or_else(|_| unreachable!()))
+    }
+
+    /// Returns the adjugate, the transpose of the cofactor matrix
+    ///
+    /// Satisfies `A adj(A) = det(A) I` even when the matrix is singular. Built from the minors of
+    /// the matrix, see [`Matrix::minor`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let m = Matrix::from([[1, 2, 3], [0, 1, 4], [5, 6, 0]]);
+    ///
+    /// assert_eq!(m.adjugate(), Matrix::from([[-24, 18, 5], [20, -15, -4], [-5, 4, 1]]));
+    /// assert_eq!(m * m.adjugate(), Matrix::from([[1, 0, 0], [0, 1, 0], [0, 0, 1]]));
+    /// ```
+    ///
+    /// Cofactors alternate in sign, so unsigned element types are not supported.
+    ///
+    /// ```compile_fail
+    /// use matrixes::Matrix;
+    ///
+    /// let _ = Matrix::from([[2_u32, 1], [1, 3]]).adjugate();
+    /// ```
+    #[must_use]
+    pub fn adjugate(&self) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.cofactor_matrix().to_transpose()
+    }
+
+    /// Returns the coefficients of the characteristic polynomial `det(λI - A)` in increasing order
+    /// of degree
+    ///
+    /// The polynomial is monic of degree `N`, so there are `N + 1` coefficients. Computed with the
+    /// Faddeev-LeVerrier algorithm, whose only divisions are exact divisions by integers, so
+    /// integer matrices give exact results as long as nothing overflows. Builds
+    /// `Mₖ = AMₖ₋₁ + cₙ₋ₖ₊₁I` with `cₙ₋ₖ = -tr(AMₖ) / k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let m = Matrix::from([[2, 1], [1, 2]]);
+    ///
+    /// // λ² - 4λ + 3
+    /// assert_eq!(m.characteristic_polynomial(), vec![3, -4, 1]);
+    /// ```
+    #[must_use]
+    pub fn characteristic_polynomial(&self) -> Vec<T> {
+        let mut coefficients = vec![T::zero(); N + 1];
+        coefficients[N] = T::one();
+
+        let mut m = Self::from_fn(|_, _| T::zero());
+        let mut k = T::zero();
+
+        for step in 1..=N {
+            k = k + T::one();
+            m = self * m;
+
+            for i in 0..N {
+                m[(i, i)] = m[(i, i)] + coefficients[N + 1 - step];
+            }
+
+            coefficients[N - step] = -(self * m).trace() / k;
+        }
+
+        coefficients
+    }
+
+    /// Returns the determinant of the matrix without row `i` and column `j` by Bareiss elimination
+    ///
+    /// The submatrix is never copied out, as its size cannot be named: rows and columns are read
+    /// through index maps that skip `i` and `j`, and row swaps only permute the row map.
+    fn submatrix_determinant(&self, i: usize, j: usize) -> T
+    where
+        T: PartialOrd,
+    {
+        let n = N.saturating_sub(1);
+        let mut a = *self;
+        let mut rows: [usize; N] = array::from_fn(|row| if row < i { row } else { row + 1 });
+        let cols: [usize; N] = array::from_fn(|col| if col < j { col } else { col + 1 });
+        let mut previous = T::one();
+        let mut negate = false;
+
+        for k in 0..n {
+            let pivot_row = (k..n).fold(k, |best, row| {
+                if a[(rows[row], cols[k])].abs() > a[(rows[best], cols[k])].abs() {
+                    row
+                } else {
+                    best
+                }
+            });
+            let pivot = a[(rows[pivot_row], cols[k])];
+
+            if pivot.is_zero() {
+                return T::zero();
+            }
+
+            if pivot_row != k {
+                rows.swap(pivot_row, k);
+                negate = !negate;
+            }
+
+            for &row in &rows[k + 1..n] {
+                let factor = a[(row, cols[k])];
+
+                for &col in &cols[k + 1..n] {
+                    a[(row, col)] = (a[(row, col)] * pivot - factor * a[(rows[k], col)]) / previous;
+                }
+            }
+
+            previous = pivot;
+        }
+
+        let det = if n == 0 { T::one() } else { previous };
+
+        if negate {
+            -det
+        } else {
+            det
+        }
+    }
+}
@@ -56,14 +56,10 @@ where
             return Err(TryFromSlicesError::Rows(value.len()));
         }
 
-        let rows: Result<Vec<_>, TryFromSlicesError> = value
-            .iter()
-            .enumerate()
-            .map(|(i, row)| {
-                <[T; C]>::try_from(&**row).map_err(|_| TryFromSlicesError::Columns(row.len(), i))
-            })
-            .collect();
+        if let Some((i, row)) = value.iter().enumerate().find(|(_, row)| row.len() != C) {
+            return Err(TryFromSlicesError::Columns(row.len(), i));
+        }
 
-        rows.map(|rows| Self(<[_; R]>::try_from(rows).unwrap_or_else(|_| unreachable!())))
+        Ok(Self::from_fn(|row, col| value[row][col]))
     }
 }
@@ -0,0 +1,301 @@
+use crate::Matrix;
+use num_traits::{Float, NumAssign};
+
+/// A matrix in row echelon form along with the columns holding its pivots
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Echelon<T, const R: usize, const C: usize> {
+    matrix: Matrix<T, R, C>,
+    pivots: Vec<usize>,
+}
+
+impl<T, const R: usize, const C: usize> Echelon<T, R, C> {
+    pub(crate) const fn new(matrix: Matrix<T, R, C>, pivots: Vec<usize>) -> Self {
+        Self { matrix, pivots }
+    }
+
+    /// Returns a reference to the reduced matrix
+    pub const fn matrix(&self) -> &Matrix<T, R, C> {
+        &self.matrix
+    }
+
+    /// Returns the columns containing pivots in increasing order
+    ///
+    /// The `i`th pivot sits in row `i`.
+    pub fn pivots(&self) -> &[usize] {
+        &self.pivots
+    }
+
+    /// Returns the reduced matrix and the pivot columns
+    pub fn into_parts(self) -> (Matrix<T, R, C>, Vec<usize>) {
+        (self.matrix, self.pivots)
+    }
+
+    /// Returns the number of pivots, the rank of the original matrix
+    pub const fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+
+    /// Returns the number of columns without a pivot, the dimension of the null space
+    pub const fn nullity(&self) -> usize {
+        C - self.rank()
+    }
+
+    /// Returns whether the matrix read as an augmented system `[A | b]` has a solution
+    ///
+    /// A system is consistent unless the last column, `b`, holds a pivot.
+    pub fn is_consistent(&self) -> bool {
+        self.pivots.last().is_none_or(|&pivot| pivot + 1 != C)
+    }
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+    T: Float + NumAssign,
+{
+    /// Returns the row echelon form of the matrix and its pivot columns
+    ///
+    /// Computed by Gaussian elimination with partial pivoting. Elements negligible relative to the
+    /// largest element of their column are treated as zero, see [`Matrix::row_echelon_with_tol`] to
+    /// choose the tolerance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let m = Matrix::from([[1.0, 2.0, 3.0], [2.0, 4.0, 7.0]]);
+    /// let echelon = m.row_echelon();
+    ///
+    /// assert_eq!(echelon.pivots(), &[0, 2]);
+    /// assert_eq!(echelon.rank(), 2);
+    /// assert_eq!(echelon.matrix()[(1, 0)], 0.0);
+    /// ```
+    #[must_use]
+    pub fn row_echelon(&self) -> Echelon<T, R, C> {
+        self.row_echelon_with_tols(self.pivot_tolerances())
+    }
+
+    /// Returns the row echelon form of the matrix and its pivot columns
+    ///
+    /// Elements with a magnitude of at most `tol` are treated as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let m = Matrix::from([[1.0, 2.0], [1.0, 2.001]]);
+    ///
+    /// assert_eq!(m.row_echelon_with_tol(1e-9).rank(), 2);
+    /// assert_eq!(m.row_echelon_with_tol(1e-2).rank(), 1);
+    /// ```
+    #[must_use]
+    pub fn row_echelon_with_tol(&self, tol: T) -> Echelon<T, R, C> {
+        self.row_echelon_with_tols([tol; C])
+    }
+
+    /// Row echelon form treating elements of each column at most its tolerance as zero
+    fn row_echelon_with_tols(&self, tols: [T; C]) -> Echelon<T, R, C> {
+        let mut matrix = *self;
+        let mut pivots = Vec::with_capacity(R.min(C));
+
+        for col in 0..C {
+            let row = pivots.len();
+
+            if row == R {
+                break;
+            }
+
+            let pivot_row = matrix.pivot_row(col, row);
+            let pivot = matrix[(pivot_row, col)];
+
+            if pivot.abs() <= tols[col] {
+                for below in row..R {
+                    matrix[(below, col)] = T::zero();
+                }
+
+                continue;
+            }
+
+            matrix
+                .swap_rows(pivot_row, row)
+                .unwrap_or_else(|_| unreachable!());
+
+            for below in row + 1..R {
+                let factor = -matrix[(below, col)] / pivot;
+                matrix
+                    .add_scaled_row(row, below, factor)
+                    .unwrap_or_else(|_| unreachable!());
+                matrix[(below, col)] = T::zero();
+            }
+
+            pivots.push(col);
+        }
+
+        Echelon::new(matrix, pivots)
+    }
+
+    /// Returns the reduced row echelon form of the matrix and its pivot columns
+    ///
+    /// Every pivot is one and is the only non-zero element in its column. Elements negligible
+    /// relative to the largest element of their column are treated as zero, see
+    /// [`Matrix::rref_with_tol`] to choose the tolerance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let m = Matrix::from([[2.0, 4.0, 2.0], [1.0, 3.0, 2.0]]);
+    /// let rref = m.rref();
+    ///
+    /// assert_eq!(rref.matrix(), &Matrix::from([[1.0, 0.0, -1.0], [0.0, 1.0, 1.0]]));
+    /// assert_eq!(rref.pivots(), &[0, 1]);
+    /// assert_eq!(rref.nullity(), 1);
+    /// ```
+    #[must_use]
+    pub fn rref(&self) -> Echelon<T, R, C> {
+        self.rref_with_tols(self.pivot_tolerances())
+    }
+
+    /// Returns the reduced row echelon form of the matrix and its pivot columns
+    ///
+    /// Elements with a magnitude of at most `tol` are treated as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let augmented = Matrix::from([[1.0, 1.0, 2.0], [1.0, 1.0 + 1e-12, 3.0]]);
+    ///
+    /// assert!(augmented.rref_with_tol(1e-15).is_consistent());
+    /// assert!(!augmented.rref_with_tol(1e-9).is_consistent());
+    /// ```
+    #[must_use]
+    pub fn rref_with_tol(&self, tol: T) -> Echelon<T, R, C> {
+        self.rref_with_tols([tol; C])
+    }
+
+    /// Reduced row echelon form treating elements of each column at most its tolerance as zero
+    fn rref_with_tols(&self, tols: [T; C]) -> Echelon<T, R, C> {
+        let (mut matrix, pivots) = self.row_echelon_with_tols(tols).into_parts();
+
+        for (row, &col) in pivots.iter().enumerate().rev() {
+            let pivot = matrix[(row, col)];
+            matrix
+                .scale_row(row, pivot.recip())
+                .unwrap_or_else(|_| unreachable!());
+            matrix[(row, col)] = T::one();
+
+            for above in 0..row {
+                let factor = -matrix[(above, col)];
+                matrix
+                    .add_scaled_row(row, above, factor)
+                    .unwrap_or_else(|_| unreachable!());
+                matrix[(above, col)] = T::zero();
+            }
+        }
+
+        Echelon::new(matrix, pivots)
+    }
+
+    /// Returns the rank of the matrix, the number of linearly independent rows or columns
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let m = Matrix::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]);
+    ///
+    /// assert_eq!(m.rank(), 1);
+    /// assert_eq!(Matrix::<f64, 3, 3>::identity().rank(), 3);
+    /// ```
+    #[must_use]
+    pub fn rank(&self) -> usize {
+        self.row_echelon().rank()
+    }
+
+    /// Returns a basis of the null space of the matrix as column vectors
+    ///
+    /// There is one vector per column of the reduced row echelon form without a pivot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let m = Matrix::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]);
+    ///
+    /// assert_eq!(
+    ///     m.null_space(),
+    ///     vec![Matrix::from([[-2.0], [1.0], [0.0]]), Matrix::from([[-3.0], [0.0], [1.0]])]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn null_space(&self) -> Vec<Matrix<T, C, 1>> {
+        let (rref, pivots) = self.rref().into_parts();
+
+        (0..C)
+            .filter(|col| !pivots.contains(col))
+            .map(|free| {
+                let mut vector = Matrix::<T, C, 1>::of_elem(T::zero());
+                vector[(free, 0)] = T::one();
+
+                for (row, &pivot) in pivots.iter().enumerate() {
+                    vector[(pivot, 0)] = -rref[(row, free)];
+                }
+
+                vector
+            })
+            .collect()
+    }
+
+    /// Returns a basis of the column space of the matrix as column vectors
+    ///
+    /// The basis is made of the columns of the matrix that hold pivots in its row echelon form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let m = Matrix::from([[1.0, 2.0, 0.0], [2.0, 4.0, 1.0]]);
+    ///
+    /// assert_eq!(
+    ///     m.column_space(),
+    ///     vec![Matrix::from([[1.0], [2.0]]), Matrix::from([[0.0], [1.0]])]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn column_space(&self) -> Vec<Matrix<T, R, 1>> {
+        self.row_echelon()
+            .pivots()
+            .iter()
+            .map(|&col| Matrix::from_fn(|row, _| self[(row, col)]))
+            .collect()
+    }
+
+    /// Returns a basis of the row space of the matrix as column vectors
+    ///
+    /// The basis is made of the non-zero rows of the reduced row echelon form of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let m = Matrix::from([[2.0, 4.0], [1.0, 2.0]]);
+    ///
+    /// assert_eq!(m.row_space(), vec![Matrix::from([[1.0], [2.0]])]);
+    /// ```
+    #[must_use]
+    pub fn row_space(&self) -> Vec<Matrix<T, C, 1>> {
+        let (rref, pivots) = self.rref().into_parts();
+
+        (0..pivots.len())
+            .map(|row| Matrix::from_fn(|col, _| rref[(row, col)]))
+            .collect()
+    }
+}
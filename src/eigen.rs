@@ -0,0 +1,397 @@
+use crate::{errors::EigenError, Givens, Matrix};
+use num_complex::Complex;
+use num_traits::{Float, NumAssign};
+use std::{array, cmp::Ordering};
+
+const DEFAULT_MAX_SWEEPS: usize = 64;
+
+/// The eigendecomposition of a real symmetric matrix, `A = VΛVᵀ`
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SymmetricEigen<T, const N: usize> {
+    eigenvalues: [T; N],
+    eigenvectors: Matrix<T, N, N>,
+}
+
+impl<T, const N: usize> SymmetricEigen<T, N> {
+    /// Returns the eigenvalues in increasing order
+    pub const fn eigenvalues(&self) -> &[T; N] {
+        &self.eigenvalues
+    }
+
+    /// Returns the orthonormal eigenvectors as columns, in the order of their eigenvalues
+    pub const fn eigenvectors(&self) -> &Matrix<T, N, N> {
+        &self.eigenvectors
+    }
+}
+
+/// The eigendecomposition of a real square matrix, `AV = VΛ`
+///
+/// Complex eigenvalues come in conjugate pairs with the positive imaginary part first, and so do
+/// their eigenvectors.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Eigen<T, const N: usize> {
+    eigenvalues: [Complex<T>; N],
+    eigenvectors: Matrix<Complex<T>, N, N>,
+}
+
+impl<T, const N: usize> Eigen<T, N> {
+    /// Returns the eigenvalues, repeated according to their algebraic multiplicity
+    pub const fn eigenvalues(&self) -> &[Complex<T>; N] {
+        &self.eigenvalues
+    }
+
+    /// Returns the eigenvectors as columns of unit length, in the order of their eigenvalues
+    ///
+    /// The eigenvectors of a defective matrix are not linearly independent.
+    pub const fn eigenvectors(&self) -> &Matrix<Complex<T>, N, N> {
+        &self.eigenvectors
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + NumAssign,
+{
+    /// Returns the eigenvalues of the matrix or an error
+    ///
+    /// The matrix is reduced to upper Hessenberg form and then to real Schur form with Francis
+    /// double-shift QR steps. Complex eigenvalues come in conjugate pairs with the positive
+    /// imaginary part first.
+    ///
+    /// # Errors
+    /// The QR iteration must converge within `30 * max(N, 10)` steps per eigenvalue
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let rotation = Matrix::from([[0.0_f64, -1.0], [1.0, 0.0]]);
+    /// let eigenvalues = rotation.eigenvalues().expect("QR iteration converges");
+    ///
+    /// assert!(eigenvalues[0].re.abs() < 1e-12 && (eigenvalues[0].im - 1.0).abs() < 1e-12);
+    /// assert!(eigenvalues[1].re.abs() < 1e-12 && (eigenvalues[1].im + 1.0).abs() < 1e-12);
+    /// ```
+    pub fn eigenvalues(&self) -> Result<[Complex<T>; N], EigenError> {
+        Ok(self.real_schur()?.eigenvalues)
+    }
+
+    /// Returns the eigenvalues and eigenvectors of the matrix or an error
+    ///
+    /// The eigenvectors are found by back substitution on the real Schur form, see
+    /// [`Matrix::eigenvalues`].
+    ///
+    /// # Errors
+    /// The QR iteration must converge within `30 * max(N, 10)` steps per eigenvalue
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[2.0_f64, 1.0], [0.0, 3.0]]);
+    /// let eigen = a.eigen().expect("QR iteration converges");
+    /// let v = eigen.eigenvectors();
+    ///
+    /// for (col, lambda) in eigen.eigenvalues().iter().enumerate() {
+    ///     for row in 0..2 {
+    ///         let av = (0..2).map(|k| v[(k, col)] * a[(row, k)]).sum::<num_complex::Complex<f64>>();
+    ///
+    ///         assert!((av - lambda * v[(row, col)]).norm() < 1e-12);
+    ///     }
+    /// }
+    /// ```
+    pub fn eigen(&self) -> Result<Eigen<T, N>, EigenError> {
+        let schur = self.real_schur()?;
+        let vectors = schur.q * quasi_triangular_eigenvectors(&schur.t, &schur.eigenvalues);
+
+        let mut eigenvectors = Matrix::from_fn(|row, col| {
+            let im = schur.eigenvalues[col].im;
+
+            if im > T::zero() {
+                Complex::new(vectors[(row, col)], vectors[(row, col + 1)])
+            } else if im < T::zero() {
+                Complex::new(vectors[(row, col - 1)], -vectors[(row, col)])
+            } else {
+                Complex::new(vectors[(row, col)], T::zero())
+            }
+        });
+
+        for col in 0..N {
+            let norm = (0..N).fold(T::zero(), |norm, row| {
+                norm.hypot(eigenvectors[(row, col)].norm())
+            });
+
+            for row in 0..N {
+                eigenvectors[(row, col)] = eigenvectors[(row, col)].unscale(norm);
+            }
+        }
+
+        Ok(Eigen {
+            eigenvalues: schur.eigenvalues,
+            eigenvectors,
+        })
+    }
+
+    /// Returns the eigenvalues and eigenvectors of a real symmetric matrix or an error
+    ///
+    /// Computed with cyclic Jacobi rotations, see [`Matrix::symmetric_eigen_with_max_iter`] to
+    /// limit the number of sweeps. The matrix is assumed to be symmetric.
+    ///
+    /// # Errors
+    /// The iteration must converge within 64 sweeps
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[2.0_f64, 1.0], [1.0, 2.0]]);
+    /// let eigen = a.symmetric_eigen().expect("jacobi converges");
+    ///
+    /// assert!((eigen.eigenvalues()[0] - 1.0).abs() < 1e-12);
+    /// assert!((eigen.eigenvalues()[1] - 3.0).abs() < 1e-12);
+    ///
+    /// let v = eigen.eigenvectors();
+    /// let error = a * v - v * Matrix::from([[1.0, 0.0], [0.0, 3.0]]);
+    ///
+    /// assert!(error.iter().all(|elem| elem.abs() < 1e-12));
+    /// ```
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<T, N>, EigenError> {
+        self.symmetric_eigen_with_max_iter(DEFAULT_MAX_SWEEPS)
+    }
+
+    /// Returns the eigenvalues and eigenvectors of a real symmetric matrix or an error
+    ///
+    /// Performs at most `max_iter` sweeps of Jacobi rotations over the off-diagonal elements.
+    ///
+    /// # Errors
+    /// The iteration must converge within `max_iter` sweeps
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::{errors::EigenError, Matrix};
+    ///
+    /// let a = Matrix::from([[4.0, 1.0, 2.0], [1.0, 3.0, 0.5], [2.0, 0.5, 1.0]]);
+    ///
+    /// assert!(a.symmetric_eigen_with_max_iter(32).is_ok());
+    /// assert_eq!(
+    ///     a.symmetric_eigen_with_max_iter(1),
+    ///     Err(EigenError::NoConvergence { iterations: 1 })
+    /// );
+    /// ```
+    pub fn symmetric_eigen_with_max_iter(
+        &self,
+        max_iter: usize,
+    ) -> Result<SymmetricEigen<T, N>, EigenError> {
+        let mut a = *self;
+        let mut v = Self::identity();
+        let mut sweeps = 0;
+
+        while !is_diagonal(&a) {
+            if sweeps == max_iter {
+                return Err(EigenError::NoConvergence {
+                    iterations: max_iter,
+                });
+            }
+
+            for p in 0..N {
+                for q in p + 1..N {
+                    if !is_significant(&a, p, q) {
+                        continue;
+                    }
+
+                    let theta =
+                        (a[(q, q)] - a[(p, p)]) / (T::from(2).unwrap_or_else(T::one) * a[(p, q)]);
+                    let t = T::one().copysign(theta) / (theta.abs() + theta.hypot(T::one()));
+                    let rotation = Givens::new(T::one(), -t);
+
+                    rotation
+                        .apply_to_cols(&mut a, p, q)
+                        .unwrap_or_else(|_| unreachable!());
+                    rotation
+                        .apply_to_rows(&mut a, p, q)
+                        .unwrap_or_else(|_| unreachable!());
+                    rotation
+                        .apply_to_cols(&mut v, p, q)
+                        .unwrap_or_else(|_| unreachable!());
+
+                    a[(p, q)] = T::zero();
+                    a[(q, p)] = T::zero();
+                }
+            }
+
+            sweeps += 1;
+        }
+
+        let mut order: [usize; N] = array::from_fn(|i| i);
+        order.sort_by(|&lhs, &rhs| {
+            a[(lhs, lhs)]
+                .partial_cmp(&a[(rhs, rhs)])
+                .unwrap_or(Ordering::Equal)
+        });
+
+        Ok(SymmetricEigen {
+            eigenvalues: order.map(|i| a[(i, i)]),
+            eigenvectors: Self::from_fn(|row, col| v[(row, order[col])]),
+        })
+    }
+}
+
+/// Whether an off-diagonal element is large relative to its diagonal elements
+fn is_significant<T, const N: usize>(matrix: &Matrix<T, N, N>, p: usize, q: usize) -> bool
+where
+    T: Float,
+{
+    let elem = matrix[(p, q)].abs();
+    let diagonal = matrix[(p, p)].abs().sqrt() * matrix[(q, q)].abs().sqrt();
+
+    elem > T::epsilon() * diagonal && elem >= T::min_positive_value()
+}
+
+fn is_diagonal<T, const N: usize>(matrix: &Matrix<T, N, N>) -> bool
+where
+    T: Float,
+{
+    (0..N).all(|p| (p + 1..N).all(|q| !is_significant(matrix, p, q)))
+}
+
+/// Returns the eigenvectors of a real Schur form by back substitution
+///
+/// Follows `hqr2` from EISPACK: a complex conjugate pair of eigenvectors is stored as its real
+/// and imaginary parts in two adjacent columns.
+#[allow(clippy::many_single_char_names, clippy::too_many_lines)]
+fn quasi_triangular_eigenvectors<T, const N: usize>(
+    t: &Matrix<T, N, N>,
+    eigenvalues: &[Complex<T>; N],
+) -> Matrix<T, N, N>
+where
+    T: Float + NumAssign,
+{
+    let mut h = *t;
+    let eps = T::epsilon();
+    let norm = h.iter().fold(T::zero(), |acc, elem| acc + elem.abs());
+
+    if norm.is_zero() {
+        return Matrix::identity();
+    }
+
+    for n in (0..N).rev() {
+        let Complex { re: p, im: q } = eigenvalues[n];
+
+        if q.is_zero() {
+            let mut l = n;
+            let (mut z, mut s) = (T::zero(), T::zero());
+            h[(n, n)] = T::one();
+
+            for i in (0..n).rev() {
+                let w = h[(i, i)] - p;
+                let r = (l..=n).fold(T::zero(), |acc, j| acc + h[(i, j)] * h[(j, n)]);
+
+                if eigenvalues[i].im < T::zero() {
+                    z = w;
+                    s = r;
+                    continue;
+                }
+
+                l = i;
+
+                if eigenvalues[i].im.is_zero() {
+                    h[(i, n)] = -r / if w.is_zero() { eps * norm } else { w };
+                } else {
+                    let (x, y) = (h[(i, i + 1)], h[(i + 1, i)]);
+                    let q = (eigenvalues[i] - p).norm_sqr();
+                    let t = (x * s - z * r) / q;
+                    h[(i, n)] = t;
+                    h[(i + 1, n)] = if x.abs() > z.abs() {
+                        (-r - w * t) / x
+                    } else {
+                        (-s - y * t) / z
+                    };
+                }
+
+                let t = h[(i, n)].abs();
+                if eps * t * t > T::one() {
+                    for j in i..=n {
+                        h[(j, n)] /= t;
+                    }
+                }
+            }
+        } else if q < T::zero() {
+            let mut l = n - 1;
+            let (mut z, mut r, mut s) = (T::zero(), T::zero(), T::zero());
+
+            if h[(n, n - 1)].abs() > h[(n - 1, n)].abs() {
+                h[(n - 1, n - 1)] = q / h[(n, n - 1)];
+                h[(n - 1, n)] = -(h[(n, n)] - p) / h[(n, n - 1)];
+            } else {
+                let c = Complex::new(T::zero(), -h[(n - 1, n)])
+                    / Complex::new(h[(n - 1, n - 1)] - p, q);
+                h[(n - 1, n - 1)] = c.re;
+                h[(n - 1, n)] = c.im;
+            }
+
+            h[(n, n - 1)] = T::zero();
+            h[(n, n)] = T::one();
+
+            for i in (0..n - 1).rev() {
+                let (ra, sa) = (l..=n).fold((T::zero(), T::zero()), |(ra, sa), j| {
+                    (ra + h[(i, j)] * h[(j, n - 1)], sa + h[(i, j)] * h[(j, n)])
+                });
+                let w = h[(i, i)] - p;
+
+                if eigenvalues[i].im < T::zero() {
+                    z = w;
+                    r = ra;
+                    s = sa;
+                    continue;
+                }
+
+                l = i;
+
+                let c = if eigenvalues[i].im.is_zero() {
+                    Complex::new(-ra, -sa) / Complex::new(w, q)
+                } else {
+                    let (x, y) = (h[(i, i + 1)], h[(i + 1, i)]);
+                    let shifted = eigenvalues[i].re - p;
+                    let mut vr = shifted * shifted + eigenvalues[i].im.powi(2) - q * q;
+                    let vi = T::from(2).unwrap_or_else(T::one) * shifted * q;
+
+                    if vr.is_zero() && vi.is_zero() {
+                        vr = eps * norm * (w.abs() + q.abs() + x.abs() + y.abs() + z.abs());
+                    }
+
+                    let c = Complex::new(x * r - z * ra + q * sa, x * s - z * sa - q * ra)
+                        / Complex::new(vr, vi);
+
+                    let next = if x.abs() > z.abs() + q.abs() {
+                        Complex::new(
+                            (-ra - w * c.re + q * c.im) / x,
+                            (-sa - w * c.im - q * c.re) / x,
+                        )
+                    } else {
+                        Complex::new(-r - y * c.re, -s - y * c.im) / Complex::new(z, q)
+                    };
+                    h[(i + 1, n - 1)] = next.re;
+                    h[(i + 1, n)] = next.im;
+
+                    c
+                };
+
+                h[(i, n - 1)] = c.re;
+                h[(i, n)] = c.im;
+
+                let t = h[(i, n - 1)].abs().max(h[(i, n)].abs());
+                if eps * t * t > T::one() {
+                    for j in i..=n {
+                        h[(j, n - 1)] /= t;
+                        h[(j, n)] /= t;
+                    }
+                }
+            }
+        }
+    }
+
+    h
+}
@@ -17,3 +17,51 @@ pub enum TryFromSlicesError {
     #[error("Wrong number of columns ({0}) in row: {1}")]
     Columns(usize, usize),
 }
+
+#[derive(Copy, Clone, Debug, Error, Eq, PartialEq)]
+pub enum InverseError {
+    #[error("Matrix is singular, no usable pivot in column: {pivot_col}")]
+    Singular { pivot_col: usize },
+}
+
+#[derive(Copy, Clone, Debug, Error, Eq, PartialEq)]
+pub enum SolveError {
+    #[error("System is singular, no usable pivot in column: {pivot_col}")]
+    Singular { pivot_col: usize },
+    #[error("Wrong right-hand side length: {found}, expected: {expected}")]
+    Dimensions { expected: usize, found: usize },
+}
+
+#[derive(Copy, Clone, Debug, Error, Eq, PartialEq)]
+pub enum CholeskyError {
+    #[error("Matrix is not positive definite, failed at pivot: {pivot}")]
+    NotPositiveDefinite { pivot: usize },
+}
+
+#[derive(Copy, Clone, Debug, Error, Eq, PartialEq)]
+pub enum EigenError {
+    #[error("Eigenvalues did not converge within {iterations} iterations")]
+    NoConvergence { iterations: usize },
+}
+
+#[derive(Copy, Clone, Debug, Error, Eq, PartialEq)]
+pub enum SvdError {
+    #[error("Singular values did not converge within {sweeps} sweeps")]
+    NoConvergence { sweeps: usize },
+}
+
+#[derive(Copy, Clone, Debug, Error, Eq, PartialEq)]
+pub enum MatrixFunctionError {
+    #[error(
+        "Matrix has an eigenvalue on the closed negative real axis, so has no principal value"
+    )]
+    NegativeRealEigenvalue,
+    #[error("Iteration reached a singular matrix")]
+    Singular,
+    #[error("Iteration did not converge within {iterations} iterations")]
+    NoConvergence { iterations: usize },
+}
+
+#[derive(Copy, Clone, Debug, Error, Eq, PartialEq)]
+#[error("Integer arithmetic overflowed")]
+pub struct OverflowError;
@@ -0,0 +1,133 @@
+use crate::{errors::OverflowError, Echelon, Matrix};
+use num_traits::{CheckedNeg, PrimInt};
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+    T: PrimInt,
+{
+    /// Returns a fraction-free row echelon form of the matrix and its pivot columns or an error
+    ///
+    /// Computed with Bareiss' algorithm: every intermediate value is the determinant of a
+    /// submatrix of the original, so each division is exact and the result stays integral. The
+    /// pivots are not scaled to one.
+    ///
+    /// # Errors
+    /// No intermediate value may overflow `T`, including going negative for unsigned types
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let m = Matrix::from([[2_i64, 4, 1], [1, 2, 3], [3, 7, 0]]);
+    /// let echelon = m.echelon_exact().expect("no overflow");
+    ///
+    /// assert_eq!(echelon.pivots(), [0, 1, 2]);
+    /// assert_eq!(
+    ///     echelon.matrix(),
+    ///     &Matrix::from([[2, 4, 1], [0, 2, -3], [0, 0, 5]])
+    /// );
+    /// ```
+    pub fn echelon_exact(&self) -> Result<Echelon<T, R, C>, OverflowError> {
+        let (matrix, pivots, _) = self.bareiss()?;
+
+        Ok(Echelon::new(matrix, pivots))
+    }
+
+    /// Returns the fraction-free echelon form, its pivot columns and whether rows were swapped
+    /// an odd number of times
+    fn bareiss(&self) -> Result<(Self, Vec<usize>, bool), OverflowError> {
+        let mut matrix = *self;
+        let mut pivots = Vec::new();
+        let mut odd_permutation = false;
+        let mut previous = T::one();
+
+        for col in 0..C {
+            let row = pivots.len();
+
+            let Some(pivot_row) = (row..R).find(|&pivot_row| !matrix[(pivot_row, col)].is_zero())
+            else {
+                continue;
+            };
+
+            matrix
+                .swap_rows(pivot_row, row)
+                .unwrap_or_else(|_| unreachable!());
+            odd_permutation ^= pivot_row != row;
+            matrix.bareiss_step(row, col, previous)?;
+
+            previous = matrix[(row, col)];
+            pivots.push(col);
+        }
+
+        Ok((matrix, pivots, odd_permutation))
+    }
+
+    /// Eliminates below the pivot at `(row, col)`, dividing exactly by the previous pivot
+    fn bareiss_step(&mut self, row: usize, col: usize, previous: T) -> Result<(), OverflowError> {
+        let pivot = self[(row, col)];
+
+        for below in row + 1..R {
+            let factor = self[(below, col)];
+
+            for rest in col + 1..C {
+                self[(below, rest)] = self[(below, rest)]
+                    .checked_mul(&pivot)
+                    .zip(factor.checked_mul(&self[(row, rest)]))
+                    .and_then(|(lhs, rhs)| lhs.checked_sub(&rhs))
+                    .and_then(|numerator| numerator.checked_div(&previous))
+                    .ok_or(OverflowError)?;
+            }
+
+            self[(below, col)] = T::zero();
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: PrimInt + CheckedNeg,
+{
+    /// Returns the determinant of the matrix computed exactly or an error
+    ///
+    /// Computed with Bareiss' fraction-free elimination in `O(N³)` checked operations, see
+    /// [`Matrix::echelon_exact`]. The determinant of a 0x0 matrix is 1.
+    ///
+    /// # Errors
+    /// No intermediate value may overflow `T`, including going negative for unsigned types
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::{errors::OverflowError, Matrix};
+    ///
+    /// let m = Matrix::from([[0_i64, 3, 1], [2, 1, 4], [5, 6, 0]]);
+    ///
+    /// assert_eq!(m.determinant_exact(), Ok(67));
+    /// assert_eq!(
+    ///     Matrix::from([[100_i8, 0], [0, 100]]).determinant_exact(),
+    ///     Err(OverflowError)
+    /// );
+    /// ```
+    pub fn determinant_exact(&self) -> Result<T, OverflowError> {
+        if N == 0 {
+            return Ok(T::one());
+        }
+
+        let (matrix, pivots, odd_permutation) = self.bareiss()?;
+
+        if pivots.len() < N {
+            return Ok(T::zero());
+        }
+
+        let det = matrix[(N - 1, N - 1)];
+
+        if odd_permutation {
+            det.checked_neg().ok_or(OverflowError)
+        } else {
+            Ok(det)
+        }
+    }
+}
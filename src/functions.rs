@@ -0,0 +1,305 @@
+use crate::{
+    errors::{EigenError, MatrixFunctionError},
+    Matrix,
+};
+
+const MAX_ITERATIONS: usize = 100;
+
+/// Pairs of Padé degree and the largest 1-norm for which it is accurate to double precision
+const PADE_THETAS: [(usize, f64); 4] = [
+    (3, 1.495_585_217_958_292e-2),
+    (5, 2.539_398_330_063_23e-1),
+    (7, 9.504_178_996_162_932e-1),
+    (9, 2.097_847_961_257_068),
+];
+const THETA_13: f64 = 5.371_920_351_148_152;
+const PADE_13: [f64; 14] = [
+    64_764_752_532_480_000.0,
+    32_382_376_266_240_000.0,
+    7_771_770_303_897_600.0,
+    1_187_353_796_428_800.0,
+    129_060_195_264_000.0,
+    10_559_470_521_600.0,
+    670_442_572_800.0,
+    33_522_128_640.0,
+    1_323_241_920.0,
+    40_840_800.0,
+    960_960.0,
+    16_380.0,
+    182.0,
+    1.0,
+];
+
+impl<const N: usize> Matrix<f64, N, N> {
+    /// Returns the matrix exponential, `eᴬ`
+    ///
+    /// Computed by scaling and squaring with a Padé approximant of degree at most 13, following
+    /// Higham's 2005 algorithm: the matrix is scaled by a power of two until its 1-norm is small
+    /// enough for the approximant to be accurate, which is then squared back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// // A rotation generator exponentiates to a rotation
+    /// let theta = std::f64::consts::FRAC_PI_3;
+    /// let rotation = Matrix::from([[0.0, -theta], [theta, 0.0]]).expm();
+    /// let expected = Matrix::from([[theta.cos(), -theta.sin()], [theta.sin(), theta.cos()]]);
+    ///
+    /// assert!((rotation - expected).iter().all(|elem| elem.abs() < 1e-14));
+    /// ```
+    #[must_use]
+    pub fn expm(&self) -> Self {
+        let norm = self.norm_1();
+        let identity = Self::identity();
+
+        if !norm.is_finite() {
+            return Self::from_fn(|_, _| f64::NAN);
+        }
+        let a2 = self * self;
+
+        for (degree, theta) in PADE_THETAS {
+            if norm <= theta {
+                let coefficients = pade_coefficients(degree);
+                let mut power = identity;
+                let mut odd = Self::new();
+                let mut even = Self::new();
+
+                for pair in coefficients.chunks(2) {
+                    even = linear_combination(&[(1.0, &even), (pair[0], &power)]);
+                    odd = linear_combination(&[(1.0, &odd), (pair[1], &power)]);
+                    power *= a2;
+                }
+
+                return pade_quotient(&(self * odd), &even);
+            }
+        }
+
+        let squarings = (norm / THETA_13).log2().ceil().max(0.0);
+        let mut a = *self;
+        a.scale(squarings.exp2().recip());
+
+        let b = PADE_13;
+        let a2 = a * a;
+        let a4 = a2 * a2;
+        let a6 = a4 * a2;
+
+        let u_high = linear_combination(&[(b[13], &a6), (b[11], &a4), (b[9], &a2)]);
+        let u_low = linear_combination(&[(b[7], &a6), (b[5], &a4), (b[3], &a2), (b[1], &identity)]);
+        let u = a * (a6 * u_high + u_low);
+
+        let v_high = linear_combination(&[(b[12], &a6), (b[10], &a4), (b[8], &a2)]);
+        let v_low = linear_combination(&[(b[6], &a6), (b[4], &a4), (b[2], &a2), (b[0], &identity)]);
+        let v = a6 * v_high + v_low;
+
+        let mut result = pade_quotient(&u, &v);
+
+        // The number of squarings is non-negative and small, as the 1-norm is finite
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        for _ in 0..squarings as u32 {
+            result = result * result;
+        }
+
+        result
+    }
+
+    /// Returns the principal square root of the matrix or an error
+    ///
+    /// The principal square root is the unique square root whose eigenvalues all have positive
+    /// real part. Computed with the Denman-Beavers iteration, scaled by determinants for faster
+    /// convergence.
+    ///
+    /// # Errors
+    /// - The matrix must not have eigenvalues on the closed negative real axis
+    /// - The iteration must converge within 100 steps without reaching a singular matrix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::{errors::MatrixFunctionError, Matrix};
+    ///
+    /// let a = Matrix::from([[33.0, 24.0], [48.0, 57.0]]);
+    /// let root = a.sqrtm().expect("a has positive eigenvalues");
+    ///
+    /// assert!((root - Matrix::from([[5.0, 2.0], [4.0, 7.0]])).iter().all(|elem| elem.abs() < 1e-12));
+    /// assert_eq!(
+    ///     Matrix::from([[-1.0, 0.0], [0.0, 1.0]]).sqrtm(),
+    ///     Err(MatrixFunctionError::NegativeRealEigenvalue)
+    /// );
+    /// ```
+    pub fn sqrtm(&self) -> Result<Self, MatrixFunctionError> {
+        self.check_principal()?;
+        self.denman_beavers()
+    }
+
+    /// Returns the principal logarithm of the matrix or an error
+    ///
+    /// The principal logarithm is the unique logarithm whose eigenvalues all have imaginary part
+    /// in `(-π, π)`. Computed by inverse scaling and squaring: square roots are taken until the
+    /// matrix is close to the identity, where the series for `2 atanh((X - I)(X + I)⁻¹)` converges
+    /// quickly.
+    ///
+    /// # Errors
+    /// - The matrix must not have eigenvalues on the closed negative real axis
+    /// - Every square root iteration must converge within 100 steps without reaching a singular
+    ///   matrix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[1.0, 2.0, 0.0], [0.0, 1.0, 0.5], [0.0, 0.0, 3.0]]);
+    /// let log = a.logm().expect("a has positive eigenvalues");
+    ///
+    /// assert!((log.expm() - a).iter().all(|elem| elem.abs() < 1e-12));
+    /// ```
+    pub fn logm(&self) -> Result<Self, MatrixFunctionError> {
+        const CLOSE_TO_IDENTITY: f64 = 0.25;
+
+        self.check_principal()?;
+
+        let identity = Self::identity();
+        let mut x = *self;
+        let mut square_roots = 0;
+        let mut factor = 1.0;
+
+        loop {
+            if (x - identity).norm_1() <= CLOSE_TO_IDENTITY {
+                break;
+            }
+
+            if square_roots == MAX_ITERATIONS {
+                return Err(MatrixFunctionError::NoConvergence {
+                    iterations: MAX_ITERATIONS,
+                });
+            }
+
+            x = x.denman_beavers()?;
+            square_roots += 1;
+            factor *= 2.0;
+        }
+
+        let z = (x + identity)
+            .try_inverse()
+            .map_err(|_| MatrixFunctionError::Singular)?
+            * (x - identity);
+        let z2 = z * z;
+        let mut power = z;
+        let mut log = Self::new();
+
+        for k in (1..).step_by(2).take(MAX_ITERATIONS) {
+            let mut term = power;
+            term.scale(2.0 / f64::from(k));
+            log += &term;
+
+            if term.norm_1() <= f64::EPSILON * log.norm_1() {
+                break;
+            }
+
+            power *= z2;
+        }
+
+        log.scale(factor);
+
+        Ok(log)
+    }
+
+    /// Checks that no eigenvalue lies on the closed negative real axis
+    fn check_principal(&self) -> Result<(), MatrixFunctionError> {
+        let eigenvalues = self.eigenvalues().map_err(|error| match error {
+            EigenError::NoConvergence { iterations } => {
+                MatrixFunctionError::NoConvergence { iterations }
+            }
+        })?;
+
+        if eigenvalues
+            .iter()
+            .any(|value| value.im.abs() <= f64::EPSILON * value.re.abs() && value.re <= 0.0)
+        {
+            return Err(MatrixFunctionError::NegativeRealEigenvalue);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the square root with the determinant-scaled Denman-Beavers iteration
+    fn denman_beavers(&self) -> Result<Self, MatrixFunctionError> {
+        let tolerance =
+            f64::EPSILON * f64::from(u32::try_from(N.max(1)).unwrap_or(u32::MAX)).sqrt();
+        let exponent = -0.5 / f64::from(u32::try_from(N.max(1)).unwrap_or(u32::MAX));
+        let mut y = *self;
+        let mut z = Self::identity();
+        let mut scaling = true;
+
+        for _ in 0..MAX_ITERATIONS {
+            let y_lu = y.lu();
+            let z_lu = z.lu();
+            let (y_inverse, z_inverse) = y_lu
+                .inverse()
+                .and_then(|y_inverse| Ok((y_inverse, z_lu.inverse()?)))
+                .map_err(|_| MatrixFunctionError::Singular)?;
+
+            let mu = if scaling {
+                (y_lu.determinant() * z_lu.determinant())
+                    .abs()
+                    .powf(exponent)
+            } else {
+                1.0
+            };
+
+            let next_y = linear_combination(&[(mu / 2.0, &y), (0.5 / mu, &z_inverse)]);
+            let next_z = linear_combination(&[(mu / 2.0, &z), (0.5 / mu, &y_inverse)]);
+            let change = (next_y - y).norm_frobenius();
+
+            y = next_y;
+            z = next_z;
+
+            if change <= tolerance * y.norm_frobenius() {
+                return Ok(y);
+            }
+
+            scaling &= change > 1e-2 * y.norm_frobenius();
+        }
+
+        Err(MatrixFunctionError::NoConvergence {
+            iterations: MAX_ITERATIONS,
+        })
+    }
+}
+
+/// Returns the coefficients of the degree `degree` Padé approximant to the exponential
+fn pade_coefficients(degree: usize) -> Vec<f64> {
+    let factorial = |n: usize| {
+        (1..=n).fold(1.0, |acc, k| {
+            acc * f64::from(u32::try_from(k).unwrap_or(u32::MAX))
+        })
+    };
+
+    (0..=degree)
+        .map(|k| {
+            factorial(2 * degree - k) * factorial(degree)
+                / (factorial(2 * degree) * factorial(k) * factorial(degree - k))
+        })
+        .collect()
+}
+
+/// Returns `(V - U)⁻¹(V + U)`, the Padé approximant with odd part `U` and even part `V`
+fn pade_quotient<const N: usize>(
+    u: &Matrix<f64, N, N>,
+    v: &Matrix<f64, N, N>,
+) -> Matrix<f64, N, N> {
+    (v - u)
+        .solve(&(v + u))
+        .unwrap_or_else(|_| Matrix::from_fn(|_, _| f64::NAN))
+}
+
+/// Returns the sum of the matrices weighted by their coefficients
+fn linear_combination<const N: usize>(terms: &[(f64, &Matrix<f64, N, N>)]) -> Matrix<f64, N, N> {
+    Matrix::from_fn(|row, col| {
+        terms.iter().fold(0.0, |acc, (coefficient, matrix)| {
+            coefficient.mul_add(matrix[(row, col)], acc)
+        })
+    })
+}
@@ -1,6 +1,6 @@
 use crate::Matrix;
-use std::mem::ManuallyDrop;
-use std::{slice, vec};
+use std::iter::Flatten;
+use std::{array, slice};
 
 impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
     pub fn iter(&self) -> <&Self as IntoIterator>::IntoIter {
@@ -14,18 +14,10 @@ impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
 
 impl<T, const R: usize, const C: usize> IntoIterator for Matrix<T, R, C> {
     type Item = T;
-    type IntoIter = vec::IntoIter<T>;
+    type IntoIter = Flatten<array::IntoIter<[T; C], R>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let this = ManuallyDrop::new(self);
-        let flattened = this.0.as_flattened();
-        let data = Vec::with_capacity(flattened.len());
-        let data_ptr = &mut data.as_slice() as *mut &[T];
-        // Safety
-        // data_ptr is valid and aligned
-        unsafe { data_ptr.write(flattened) }
-
-        data.into_iter()
+        self.0.into_iter().flatten()
     }
 }
 
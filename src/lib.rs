@@ -5,8 +5,30 @@ mod traits;
 mod iter;
 pub mod errors;
 mod maths;
+mod echelon;
+mod lu;
+mod qr;
+mod cholesky;
+mod svd;
+mod schur;
+mod eigen;
+mod norms;
+mod powers;
+mod functions;
+mod cofactor;
+mod exact;
+mod normal_form;
+mod products;
 #[cfg(feature = "serde")]
 mod serde;
 
+pub use echelon::Echelon;
+pub use lu::Lu;
+pub use cholesky::{Cholesky, Ldlt};
+pub use svd::Svd;
+pub use eigen::{Eigen, SymmetricEigen};
+pub use transformers::{Givens, Householder};
+pub use norms::Norm;
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub struct Matrix<T, const R: usize, const C: usize>([[T; C]; R]);
\ No newline at end of file
@@ -0,0 +1,444 @@
+use crate::{
+    errors::{InverseError, SolveError},
+    Matrix,
+};
+use num_traits::{Float, NumAssign};
+use std::{array, cmp::Ordering};
+
+/// An LU factorisation with partial pivoting, `PA = LU`
+///
+/// The factors are stored packed in a single matrix: `U` on and above the diagonal and the
+/// multipliers of the unit lower triangular `L` below it. Factorising once lets any number of
+/// right-hand sides be solved in `O(N²)` each.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Lu<T, const N: usize> {
+    factors: Matrix<T, N, N>,
+    permutation: [usize; N],
+    odd_permutation: bool,
+    singular_col: Option<usize>,
+    norm_1: T,
+}
+
+impl<T, const N: usize> Lu<T, N>
+where
+    T: Float + NumAssign,
+{
+    fn new(matrix: &Matrix<T, N, N>) -> Self {
+        let tolerances = matrix.pivot_tolerances();
+        let mut factors = *matrix;
+        let mut permutation = array::from_fn(|row| row);
+        let mut odd_permutation = false;
+        let mut singular_col = None;
+
+        for col in 0..N {
+            let pivot_row = factors.pivot_row(col, col);
+            factors
+                .swap_rows(pivot_row, col)
+                .unwrap_or_else(|_| unreachable!());
+            permutation.swap(pivot_row, col);
+            odd_permutation ^= pivot_row != col;
+
+            let pivot = factors[(col, col)];
+
+            if pivot.abs() <= tolerances[col] {
+                singular_col.get_or_insert(col);
+                continue;
+            }
+
+            for row in col + 1..N {
+                let multiplier = factors[(row, col)] / pivot;
+                factors[(row, col)] = multiplier;
+
+                for rest in col + 1..N {
+                    let upper = factors[(col, rest)];
+                    factors[(row, rest)] -= multiplier * upper;
+                }
+            }
+        }
+
+        Self {
+            factors,
+            permutation,
+            odd_permutation,
+            singular_col,
+            norm_1: matrix.norm_1(),
+        }
+    }
+
+    /// Returns the unit lower triangular factor `L`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let lu = Matrix::from([[2.0, 1.0], [4.0, 5.0]]).lu();
+    ///
+    /// assert_eq!(lu.l(), Matrix::from([[1.0, 0.0], [0.5, 1.0]]));
+    /// ```
+    pub fn l(&self) -> Matrix<T, N, N> {
+        Matrix::from_fn(|row, col| match row.cmp(&col) {
+            Ordering::Greater => self.factors[(row, col)],
+            Ordering::Equal => T::one(),
+            Ordering::Less => T::zero(),
+        })
+    }
+
+    /// Returns the upper triangular factor `U`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let lu = Matrix::from([[2.0, 1.0], [4.0, 5.0]]).lu();
+    ///
+    /// assert_eq!(lu.u(), Matrix::from([[4.0, 5.0], [0.0, -1.5]]));
+    /// ```
+    pub fn u(&self) -> Matrix<T, N, N> {
+        Matrix::from_fn(|row, col| {
+            if row <= col {
+                self.factors[(row, col)]
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Returns the row permutation `P` as a permutation matrix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[1.0, 2.0, 0.0], [3.0, 1.0, 1.0], [0.0, 5.0, 2.0]]);
+    /// let lu = a.lu();
+    /// let error = lu.p() * a - lu.l() * lu.u();
+    ///
+    /// assert!(error.iter().all(|elem: &f64| elem.abs() < 1e-12));
+    /// ```
+    pub fn p(&self) -> Matrix<T, N, N> {
+        Matrix::from_fn(|row, col| {
+            if self.permutation[row] == col {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Returns the determinant of the factorised matrix
+    ///
+    /// Singular matrices have a determinant of exactly zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// assert_eq!(Matrix::from([[0.0, 2.0], [3.0, 0.0]]).lu().determinant(), -6.0);
+    /// assert_eq!(Matrix::from([[1.0, 2.0], [2.0, 4.0]]).lu().determinant(), 0.0);
+    /// ```
+    pub fn determinant(&self) -> T {
+        if self.is_singular() {
+            return T::zero();
+        }
+
+        let det = (0..N).fold(T::one(), |det, i| det * self.factors[(i, i)]);
+
+        if self.odd_permutation {
+            -det
+        } else {
+            det
+        }
+    }
+
+    /// Returns the inverse of the factorised matrix or an error
+    ///
+    /// # Errors
+    /// The factorised matrix must not be singular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::{errors::InverseError, Matrix};
+    ///
+    /// let lu = Matrix::from([[0.0, 2.0], [4.0, 0.0]]).lu();
+    /// let singular = Matrix::from([[1.0, 2.0], [2.0, 4.0]]).lu();
+    ///
+    /// assert_eq!(lu.inverse(), Ok(Matrix::from([[0.0, 0.25], [0.5, 0.0]])));
+    /// assert_eq!(singular.inverse(), Err(InverseError::Singular { pivot_col: 1 }));
+    /// ```
+    pub fn inverse(&self) -> Result<Matrix<T, N, N>, InverseError> {
+        if let Some(pivot_col) = self.singular_col {
+            return Err(InverseError::Singular { pivot_col });
+        }
+
+        Ok(self
+            .solve(&Matrix::identity())
+            .unwrap_or_else(|_| unreachable!()))
+    }
+
+    /// Returns whether a pivot of the factorisation was negligible
+    pub const fn is_singular(&self) -> bool {
+        self.singular_col.is_some()
+    }
+
+    /// Returns the solution `x` of `Ax = b` for every column of `b` or an error
+    ///
+    /// # Errors
+    /// The factorised matrix must not be singular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[2.0_f64, 1.0], [1.0, 3.0]]);
+    /// let lu = a.lu();
+    ///
+    /// let x = lu.solve(&Matrix::from([[3.0], [4.0]])).expect("a is not singular");
+    /// let y = lu.solve(&Matrix::from([[1.0], [-2.0]])).expect("a is not singular");
+    ///
+    /// assert!((x[(0, 0)] - 1.0).abs() < 1e-12 && (x[(1, 0)] - 1.0).abs() < 1e-12);
+    /// assert!((y[(0, 0)] - 1.0).abs() < 1e-12 && (y[(1, 0)] + 1.0).abs() < 1e-12);
+    /// ```
+    pub fn solve<const K: usize>(
+        &self,
+        b: &Matrix<T, N, K>,
+    ) -> Result<Matrix<T, N, K>, SolveError> {
+        self.check_singular()?;
+
+        let mut x = *b;
+
+        for col in 0..K {
+            let solution = self.substitute(array::from_fn(|row| b[(self.permutation[row], col)]));
+
+            for (row, elem) in solution.into_iter().enumerate() {
+                x[(row, col)] = elem;
+            }
+        }
+
+        Ok(x)
+    }
+
+    /// Overwrites `b` with the solution `x` of `Ax = b` or returns an error
+    ///
+    /// # Errors
+    /// - The factorised matrix must not be singular
+    /// - `b` must have one element per row of the factorised matrix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::{errors::SolveError, Matrix};
+    ///
+    /// let lu = Matrix::from([[4.0, 0.0], [0.0, 2.0]]).lu();
+    /// let mut b = vec![8.0, 3.0];
+    ///
+    /// assert_eq!(lu.solve_in_place(&mut b), Ok(()));
+    /// assert_eq!(b, vec![2.0, 1.5]);
+    /// assert_eq!(
+    ///     lu.solve_in_place(&mut [1.0, 2.0, 3.0]),
+    ///     Err(SolveError::Dimensions { expected: 2, found: 3 })
+    /// );
+    /// ```
+    pub fn solve_in_place(&self, b: &mut [T]) -> Result<(), SolveError> {
+        if b.len() != N {
+            return Err(SolveError::Dimensions {
+                expected: N,
+                found: b.len(),
+            });
+        }
+
+        self.check_singular()?;
+
+        let solution = self.substitute(array::from_fn(|row| b[self.permutation[row]]));
+        b.copy_from_slice(&solution);
+
+        Ok(())
+    }
+
+    /// Returns an estimate of the 1-norm condition number, `‖A‖₁‖A⁻¹‖₁`
+    ///
+    /// `‖A⁻¹‖₁` is estimated with Hager's method as refined by Higham, which takes a handful of
+    /// `O(N²)` solves with the existing factors instead of forming the inverse. The estimate is a
+    /// lower bound and is almost always within a small factor of the true value. Singular matrices
+    /// have an infinite condition number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::{Matrix, Norm};
+    ///
+    /// let a = Matrix::from([[4.0_f64, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 2.0, 1e-4]]);
+    /// let estimate = a.lu().condition_number_estimate();
+    /// let exact = a.condition_number(Norm::One);
+    ///
+    /// assert!(estimate <= exact * (1.0 + 1e-12) && estimate >= exact / 3.0);
+    /// ```
+    pub fn condition_number_estimate(&self) -> T {
+        const MAX_ITERATIONS: usize = 5;
+
+        if self.is_singular() {
+            return T::infinity();
+        }
+
+        if N == 0 {
+            return T::one();
+        }
+
+        let n = T::from(N).unwrap_or_else(T::one);
+        let mut x = [n.recip(); N];
+        let mut estimate = T::zero();
+        let mut previous = None;
+
+        for _ in 0..MAX_ITERATIONS {
+            let y = self.substitute(array::from_fn(|row| x[self.permutation[row]]));
+            estimate = y.iter().fold(T::zero(), |acc, elem| acc + elem.abs());
+
+            let signs = y.map(|elem| {
+                if elem < T::zero() {
+                    -T::one()
+                } else {
+                    T::one()
+                }
+            });
+            let z = self.substitute_transpose(signs);
+            let largest = (0..N).fold(0, |best, row| {
+                if z[row].abs() > z[best].abs() {
+                    row
+                } else {
+                    best
+                }
+            });
+            let dot = (0..N).fold(T::zero(), |acc, row| acc + z[row] * x[row]);
+
+            if z[largest].abs() <= dot || previous == Some(largest) {
+                break;
+            }
+
+            previous = Some(largest);
+            x = array::from_fn(|row| if row == largest { T::one() } else { T::zero() });
+        }
+
+        let denominator = T::from(N.max(2) - 1).unwrap_or_else(T::one);
+        let alternating: [T; N] = array::from_fn(|row| {
+            let elem = T::one() + T::from(row).unwrap_or_else(T::zero) / denominator;
+            if row % 2 == 0 {
+                elem
+            } else {
+                -elem
+            }
+        });
+        let y = self.substitute(array::from_fn(|row| alternating[self.permutation[row]]));
+        let alternative = T::from(2).unwrap_or_else(T::one)
+            * y.iter().fold(T::zero(), |acc, elem| acc + elem.abs())
+            / (T::from(3).unwrap_or_else(T::one) * n);
+
+        self.norm_1 * estimate.max(alternative)
+    }
+
+    const fn check_singular(&self) -> Result<(), SolveError> {
+        match self.singular_col {
+            Some(pivot_col) => Err(SolveError::Singular { pivot_col }),
+            None => Ok(()),
+        }
+    }
+
+    /// Solves `LUx = y` where `y` is the permuted right-hand side
+    fn substitute(&self, mut x: [T; N]) -> [T; N] {
+        for row in 0..N {
+            for col in 0..row {
+                let elem = x[col];
+                x[row] -= self.factors[(row, col)] * elem;
+            }
+        }
+
+        for row in (0..N).rev() {
+            for col in row + 1..N {
+                let elem = x[col];
+                x[row] -= self.factors[(row, col)] * elem;
+            }
+
+            x[row] /= self.factors[(row, row)];
+        }
+
+        x
+    }
+
+    /// Solves `Aᵀx = b` by substituting through `Uᵀ` and `Lᵀ` then undoing the permutation
+    fn substitute_transpose(&self, mut y: [T; N]) -> [T; N] {
+        for row in 0..N {
+            for col in 0..row {
+                let elem = y[col];
+                y[row] -= self.factors[(col, row)] * elem;
+            }
+
+            y[row] /= self.factors[(row, row)];
+        }
+
+        for row in (0..N).rev() {
+            for col in row + 1..N {
+                let elem = y[col];
+                y[row] -= self.factors[(col, row)] * elem;
+            }
+        }
+
+        let mut x = y;
+        for (row, &original) in self.permutation.iter().enumerate() {
+            x[original] = y[row];
+        }
+
+        x
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + NumAssign,
+{
+    /// Returns the LU factorisation of the matrix with partial pivoting
+    ///
+    /// Factorisation always succeeds; singularity is reported when solving.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// assert!(!Matrix::from([[0.0, 1.0], [1.0, 0.0]]).lu().is_singular());
+    /// assert!(Matrix::from([[1.0, 2.0], [2.0, 4.0]]).lu().is_singular());
+    /// ```
+    #[must_use]
+    pub fn lu(&self) -> Lu<T, N> {
+        Lu::new(self)
+    }
+
+    /// Returns the solution `x` of `Ax = b` for every column of `b` or an error
+    ///
+    /// Factorises the matrix on every call, see [`Matrix::lu`] to reuse a factorisation.
+    ///
+    /// # Errors
+    /// The matrix must not be singular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::{errors::SolveError, Matrix};
+    ///
+    /// let a = Matrix::from([[0.0, 2.0], [4.0, 0.0]]);
+    /// let singular = Matrix::from([[1.0, 1.0], [1.0, 1.0]]);
+    /// let b = Matrix::from([[2.0, 4.0], [8.0, 4.0]]);
+    ///
+    /// assert_eq!(a.solve(&b), Ok(Matrix::from([[2.0, 1.0], [1.0, 2.0]])));
+    /// assert_eq!(singular.solve(&b), Err(SolveError::Singular { pivot_col: 1 }));
+    /// ```
+    pub fn solve<const K: usize>(
+        &self,
+        b: &Matrix<T, N, K>,
+    ) -> Result<Matrix<T, N, K>, SolveError> {
+        self.lu().solve(b)
+    }
+}
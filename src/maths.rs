@@ -1,9 +1,13 @@
-use crate::{errors::IndexError, Matrix};
-use num_traits::{ConstZero, One, Zero};
+use crate::{
+    errors::{IndexError, InverseError},
+    Matrix,
+};
+use num_traits::{ConstZero, Float, NumAssign, One, Zero};
 use std::ops::{Sub, SubAssign};
 use std::{
+    array,
     iter::zip,
-    ops::{Add, AddAssign, Mul, MulAssign},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign},
 };
 
 impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
@@ -137,6 +141,189 @@ impl<T, const N: usize> Matrix<T, N, N> {
     {
         (0..N).fold(T::zero(), |acc, i| acc + self[(i, i)])
     }
+
+    /// Returns the determinant of the matrix
+    ///
+    /// Computed by LU factorisation with partial pivoting. Pivots that are negligible relative to
+    /// the largest element of their column are treated as zero, so singular matrices give exactly
+    /// zero rather than rounding noise. The determinant of a 0x0 matrix is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let m = Matrix::from([[2.0_f64, 1.0], [4.0, 5.0]]);
+    /// let singular = Matrix::from([[0.1, 0.2], [0.3, 0.6]]);
+    ///
+    /// assert!((m.det() - 6.0).abs() < 1e-12);
+    /// assert_eq!(singular.det(), 0.0);
+    /// assert_eq!(Matrix::<f64, 0, 0>::new().det(), 1.0);
+    /// ```
+    pub fn det(&self) -> T
+    where
+        T: Float + NumAssign,
+    {
+        let tolerances = self.pivot_tolerances();
+        let mut lu = *self;
+        let mut det = T::one();
+
+        for col in 0..N {
+            let pivot_row = lu.pivot_row(col, col);
+            let pivot = lu[(pivot_row, col)];
+
+            if pivot.abs() <= tolerances[col] {
+                return T::zero();
+            }
+
+            if pivot_row != col {
+                lu.swap_rows(pivot_row, col)
+                    .unwrap_or_else(|_| unreachable!());
+                det = -det;
+            }
+
+            det *= pivot;
+
+            for row in col + 1..N {
+                let factor = -lu[(row, col)] / pivot;
+                lu.add_scaled_row(col, row, factor)
+                    .unwrap_or_else(|_| unreachable!());
+            }
+        }
+
+        det
+    }
+
+    /// Returns the inverse of the matrix or an error
+    ///
+    /// Computed by Gauss-Jordan elimination with partial pivoting, see [`Matrix::invert_in_place`].
+    ///
+    /// # Errors
+    /// The matrix must not be singular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::{errors::InverseError, Matrix};
+    ///
+    /// let m = Matrix::from([[4.0, 7.0], [2.0, 6.0]]);
+    /// let singular = Matrix::from([[1.0, 2.0], [2.0, 4.0]]);
+    ///
+    /// let inverse = m.try_inverse().expect("m is not singular");
+    /// let error = m * inverse - Matrix::identity();
+    ///
+    /// assert!(error.iter().all(|elem: &f64| elem.abs() < 1e-12));
+    /// assert_eq!(singular.try_inverse(), Err(InverseError::Singular { pivot_col: 1 }));
+    /// ```
+    pub fn try_inverse(&self) -> Result<Self, InverseError>
+    where
+        T: Float + NumAssign,
+    {
+        let mut inverse = *self;
+        inverse.invert_in_place()?;
+
+        Ok(inverse)
+    }
+
+    /// Replaces the matrix with its inverse or returns an error
+    ///
+    /// Computed by Gauss-Jordan elimination with partial pivoting without copying the matrix.
+    /// If the matrix is singular its contents are left partially eliminated.
+    ///
+    /// # Errors
+    /// The matrix must not be singular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let mut m = Matrix::from([[0.0, 2.0], [4.0, 0.0]]);
+    /// m.invert_in_place().expect("m is not singular");
+    ///
+    /// assert_eq!(m, Matrix::from([[0.0, 0.25], [0.5, 0.0]]));
+    /// ```
+    pub fn invert_in_place(&mut self) -> Result<(), InverseError>
+    where
+        T: Float + NumAssign,
+    {
+        let tolerances = self.pivot_tolerances();
+        let mut swaps = [0; N];
+
+        for col in 0..N {
+            let pivot_row = self.pivot_row(col, col);
+            let pivot = self[(pivot_row, col)];
+
+            if pivot.abs() <= tolerances[col] {
+                return Err(InverseError::Singular { pivot_col: col });
+            }
+
+            swaps[col] = pivot_row;
+            self.swap_rows(pivot_row, col)
+                .unwrap_or_else(|_| unreachable!());
+
+            self[(col, col)] = T::one();
+            self.scale_row(col, pivot.recip())
+                .unwrap_or_else(|_| unreachable!());
+
+            for row in (0..N).filter(|&row| row != col) {
+                let factor = self[(row, col)];
+                self[(row, col)] = T::zero();
+                self.add_scaled_row(col, row, -factor)
+                    .unwrap_or_else(|_| unreachable!());
+            }
+        }
+
+        for (col, &swapped) in swaps.iter().enumerate().rev() {
+            self.swap_cols(col, swapped)
+                .unwrap_or_else(|_| unreachable!());
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+    T: Float,
+{
+    /// The magnitude below which a pivot in each column is treated as zero during elimination
+    ///
+    /// Each tolerance is relative to the largest element of its column in the original matrix,
+    /// so columns of very different scales do not swamp each other. The pivot found for a column
+    /// after elimination is compared with that column's tolerance.
+    pub(crate) fn pivot_tolerances(&self) -> [T; C] {
+        let dim = T::from(R.max(C)).unwrap_or_else(T::one);
+
+        array::from_fn(|col| {
+            let max = (0..R).fold(T::zero(), |max, row| max.max(self[(row, col)].abs()));
+
+            T::epsilon() * dim * max
+        })
+    }
+
+    /// The row at or below `from` holding the largest magnitude element of `col`
+    pub(crate) fn pivot_row(&self, col: usize, from: usize) -> usize {
+        (from..R).fold(from, |best, row| {
+            if self[(row, col)].abs() > self[(best, col)].abs() {
+                row
+            } else {
+                best
+            }
+        })
+    }
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Combines the corresponding elements of two matrices, building the result in place
+    fn zip_map<U, V, F>(self, rhs: Matrix<U, R, C>, mut f: F) -> Matrix<V, R, C>
+    where
+        F: FnMut(T, U) -> V,
+    {
+        let mut rhs = rhs.into_iter();
+
+        self.map(|lhs| f(lhs, rhs.next().unwrap_or_else(|| unreachable!())))
+    }
 }
 
 impl<T, const R: usize, const C: usize> Add for Matrix<T, R, C>
@@ -145,20 +332,8 @@ where
 {
     type Output = Matrix<<T as Add>::Output, R, C>;
 
-    #[allow(clippy::op_ref)]
     fn add(self, rhs: Self) -> Self::Output {
-        let rows_vec: Vec<_> = zip(self.0, rhs.0)
-            .map(|(lhs, rhs)| {
-                <[_; C]>::try_from(
-                    zip(lhs, rhs)
-                        .map(|(lhs, rhs)| lhs + rhs)
-                        .collect::<Vec<_>>(),
-                )
-                .unwrap_or_else(|_| unreachable!())
-            })
-            .collect();
-
-        Matrix::from(<[_; R]>::try_from(rows_vec).unwrap_or_else(|_| unreachable!()))
+        self.zip_map(rhs, |lhs, rhs| lhs + rhs)
     }
 }
 
@@ -193,18 +368,7 @@ where
     type Output = Matrix<<T as Add>::Output, R, C>;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let rows_vec: Vec<_> = zip(&self.0, &rhs.0)
-            .map(|(lhs, rhs)| {
-                <[_; C]>::try_from(
-                    zip(lhs, rhs)
-                        .map(|(lhs, rhs)| *lhs + *rhs)
-                        .collect::<Vec<_>>(),
-                )
-                .unwrap_or_else(|_| unreachable!())
-            })
-            .collect();
-
-        Matrix::from(<[_; R]>::try_from(rows_vec).unwrap_or_else(|_| unreachable!()))
+        Matrix::from_fn(|row, col| self.0[row][col] + rhs.0[row][col])
     }
 }
 
@@ -237,18 +401,7 @@ where
     type Output = Matrix<<T as Sub>::Output, R, C>;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let rows_vec: Vec<_> = zip(self.0, rhs.0)
-            .map(|(lhs, rhs)| {
-                <[_; C]>::try_from(
-                    zip(lhs, rhs)
-                        .map(|(lhs, rhs)| lhs - rhs)
-                        .collect::<Vec<_>>(),
-                )
-                .unwrap_or_else(|_| unreachable!())
-            })
-            .collect();
-
-        Matrix(<[_; R]>::try_from(rows_vec).unwrap_or_else(|_| unreachable!()))
+        self.zip_map(rhs, |lhs, rhs| lhs - rhs)
     }
 }
 
@@ -283,18 +436,7 @@ where
     type Output = Matrix<<T as Sub>::Output, R, C>;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let rows_vec: Vec<_> = zip(&self.0, &rhs.0)
-            .map(|(lhs, rhs)| {
-                <[_; C]>::try_from(
-                    zip(lhs, rhs)
-                        .map(|(lhs, rhs)| *lhs - *rhs)
-                        .collect::<Vec<_>>(),
-                )
-                .unwrap_or_else(|_| unreachable!())
-            })
-            .collect();
-
-        Matrix(<[_; R]>::try_from(rows_vec).unwrap_or_else(|_| unreachable!()))
+        Matrix::from_fn(|row, col| self.0[row][col] - rhs.0[row][col])
     }
 }
 
@@ -362,13 +504,307 @@ where
     type Output = Matrix<T, R, C>;
 
     fn mul(self, rhs: &Matrix<T, S, C>) -> Self::Output {
-        Matrix::from_fn(|i, j| {
-            zip(self.get_row(i).expect(""), rhs.get_col(j).expect(""))
-                .fold(T::zero(), |acc, (lhs, rhs)| acc + *lhs * *rhs)
-        })
+        let mut out = Matrix::zero();
+        self.accumulate_product(rhs, &mut out, |elem| elem);
+
+        out
+    }
+}
+
+impl<T, const N: usize> MulAssign for Matrix<T, N, N>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Copy,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self *= &rhs;
+    }
+}
+
+impl<T, const N: usize> MulAssign<&Self> for Matrix<T, N, N>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Copy,
+{
+    fn mul_assign(&mut self, rhs: &Self) {
+        let lhs = *self;
+        lhs.mul_into(rhs, self);
+    }
+}
+
+impl<T, const R: usize, const S: usize> Matrix<T, R, S>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Copy,
+{
+    /// Writes the product `self * rhs` into `out`, overwriting its contents
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+    /// let b = Matrix::from([[1, 0, -1], [2, 1, 0]]);
+    /// let mut out = Matrix::new();
+    /// a.mul_into(&b, &mut out);
+    ///
+    /// assert_eq!(out, a * b);
+    /// ```
+    pub fn mul_into<const C: usize>(&self, rhs: &Matrix<T, S, C>, out: &mut Matrix<T, R, C>) {
+        for elem in &mut *out {
+            *elem = T::zero();
+        }
+
+        self.accumulate_product(rhs, out, |elem| elem);
+    }
+
+    /// Adds `scale(aᵢₖ) * bₖⱼ` to every element `(i, j)` of `out`
+    ///
+    /// Loops in i-k-j order over blocks of `k` and `j`, so the innermost loop walks contiguous
+    /// rows of `rhs` and `out` without bounds checks and each block of `rhs` stays in cache while
+    /// every row of `self` is applied to it. Each element still accumulates in ascending `k`.
+    fn accumulate_product<const C: usize, F>(
+        &self,
+        rhs: &Matrix<T, S, C>,
+        out: &mut Matrix<T, R, C>,
+        scale: F,
+    ) where
+        F: Fn(T) -> T,
+    {
+        const BLOCK: usize = 64;
+
+        for k_start in (0..S).step_by(BLOCK) {
+            let k_range = k_start..(k_start + BLOCK).min(S);
+
+            for j_start in (0..C).step_by(BLOCK) {
+                let j_range = j_start..(j_start + BLOCK).min(C);
+
+                for (out_row, lhs_row) in zip(&mut out.0, &self.0) {
+                    let out_block = &mut out_row[j_range.clone()];
+
+                    for (lhs, rhs_row) in zip(&lhs_row[k_range.clone()], &rhs.0[k_range.clone()]) {
+                        let lhs = scale(*lhs);
+
+                        for (out, rhs) in zip(&mut *out_block, &rhs_row[j_range.clone()]) {
+                            *out = *out + lhs * *rhs;
+                        }
+                    }
+                }
+            }
+        }
     }
 }
 
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Copy,
+{
+    /// Computes the general matrix product `c = alpha * a * b + beta * c` in place
+    ///
+    /// As in BLAS, `c` is not read when `beta` is zero, so it may hold uninitialised values such
+    /// as NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+    /// let b = Matrix::from([[0.5, 0.0], [0.0, 0.5]]);
+    /// let mut c = Matrix::from([[1.0, 1.0], [1.0, 1.0]]);
+    /// Matrix::gemm(2.0, &a, &b, 3.0, &mut c);
+    ///
+    /// assert_eq!(c, Matrix::from([[4.0, 5.0], [6.0, 7.0]]));
+    /// ```
+    pub fn gemm<const S: usize>(
+        alpha: T,
+        a: &Matrix<T, R, S>,
+        b: &Matrix<T, S, C>,
+        beta: T,
+        c: &mut Self,
+    ) {
+        for elem in &mut *c {
+            *elem = if beta.is_zero() {
+                T::zero()
+            } else {
+                beta * *elem
+            };
+        }
+
+        a.accumulate_product(b, c, |elem| alpha * elem);
+    }
+}
+
+impl<T, const R: usize, const C: usize> Mul<T> for Matrix<T, R, C>
+where
+    T: Mul + Copy,
+{
+    type Output = Matrix<<T as Mul>::Output, R, C>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Matrix::from(self.0.map(|row| row.map(|elem| elem * rhs)))
+    }
+}
+
+impl<T, const R: usize, const C: usize> Mul<T> for &Matrix<T, R, C>
+where
+    T: Mul + Copy,
+{
+    type Output = Matrix<<T as Mul>::Output, R, C>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        *self * rhs
+    }
+}
+
+impl<T, const R: usize, const C: usize> Mul<&T> for Matrix<T, R, C>
+where
+    T: Mul + Copy,
+{
+    type Output = Matrix<<T as Mul>::Output, R, C>;
+
+    fn mul(self, rhs: &T) -> Self::Output {
+        self * *rhs
+    }
+}
+
+impl<T, const R: usize, const C: usize> Mul<&T> for &Matrix<T, R, C>
+where
+    T: Mul + Copy,
+{
+    type Output = Matrix<<T as Mul>::Output, R, C>;
+
+    fn mul(self, rhs: &T) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+impl<T, const R: usize, const C: usize> MulAssign<T> for Matrix<T, R, C>
+where
+    T: MulAssign + Copy,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        self.scale(rhs);
+    }
+}
+
+impl<T, const R: usize, const C: usize> MulAssign<&T> for Matrix<T, R, C>
+where
+    T: MulAssign + Copy,
+{
+    fn mul_assign(&mut self, rhs: &T) {
+        self.scale(*rhs);
+    }
+}
+
+impl<T, const R: usize, const C: usize> Div<T> for Matrix<T, R, C>
+where
+    T: Div + Copy,
+{
+    type Output = Matrix<<T as Div>::Output, R, C>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Matrix::from(self.0.map(|row| row.map(|elem| elem / rhs)))
+    }
+}
+
+impl<T, const R: usize, const C: usize> Div<T> for &Matrix<T, R, C>
+where
+    T: Div + Copy,
+{
+    type Output = Matrix<<T as Div>::Output, R, C>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        *self / rhs
+    }
+}
+
+impl<T, const R: usize, const C: usize> Div<&T> for Matrix<T, R, C>
+where
+    T: Div + Copy,
+{
+    type Output = Matrix<<T as Div>::Output, R, C>;
+
+    fn div(self, rhs: &T) -> Self::Output {
+        self / *rhs
+    }
+}
+
+impl<T, const R: usize, const C: usize> Div<&T> for &Matrix<T, R, C>
+where
+    T: Div + Copy,
+{
+    type Output = Matrix<<T as Div>::Output, R, C>;
+
+    fn div(self, rhs: &T) -> Self::Output {
+        *self / *rhs
+    }
+}
+
+impl<T, const R: usize, const C: usize> DivAssign<T> for Matrix<T, R, C>
+where
+    T: DivAssign + Copy,
+{
+    fn div_assign(&mut self, rhs: T) {
+        for elem in self {
+            *elem /= rhs;
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize> DivAssign<&T> for Matrix<T, R, C>
+where
+    T: DivAssign + Copy,
+{
+    fn div_assign(&mut self, rhs: &T) {
+        *self /= *rhs;
+    }
+}
+
+/// Implements left scalar multiplication, `T * Matrix`, for primitive numeric types, owned and
+/// borrowed
+///
+/// A blanket impl is not possible as `T` would be an uncovered type parameter.
+macro_rules! impl_scalar_mul {
+    ($($t:ty),*) => {
+        $(
+            impl<const R: usize, const C: usize> Mul<Matrix<$t, R, C>> for $t {
+                type Output = Matrix<$t, R, C>;
+
+                fn mul(self, rhs: Matrix<$t, R, C>) -> Self::Output {
+                    rhs * self
+                }
+            }
+
+            impl<const R: usize, const C: usize> Mul<&Matrix<$t, R, C>> for $t {
+                type Output = Matrix<$t, R, C>;
+
+                fn mul(self, rhs: &Matrix<$t, R, C>) -> Self::Output {
+                    rhs * self
+                }
+            }
+
+            impl<const R: usize, const C: usize> Mul<Matrix<$t, R, C>> for &$t {
+                type Output = Matrix<$t, R, C>;
+
+                fn mul(self, rhs: Matrix<$t, R, C>) -> Self::Output {
+                    rhs * *self
+                }
+            }
+
+            impl<const R: usize, const C: usize> Mul<&Matrix<$t, R, C>> for &$t {
+                type Output = Matrix<$t, R, C>;
+
+                fn mul(self, rhs: &Matrix<$t, R, C>) -> Self::Output {
+                    rhs * *self
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_mul!(i8, i16, i32, i64, i128, isize);
+impl_scalar_mul!(u8, u16, u32, u64, u128, usize);
+impl_scalar_mul!(f32, f64);
+
 impl<T, const R: usize, const C: usize> Zero for Matrix<T, R, C>
 where
     T: Add<Output = T> + Zero,
@@ -0,0 +1,301 @@
+use crate::{errors::OverflowError, Matrix};
+use num_traits::{PrimInt, Signed};
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+    T: PrimInt + Signed,
+{
+    /// Returns the Hermite normal form `H` of the matrix and a unimodular `U` with `UA = H`, or an
+    /// error
+    ///
+    /// `H` is in row echelon form with positive pivots, and every element above a pivot lies in
+    /// `[0, pivot)`. It is unique for each matrix and shares its row lattice.
+    ///
+    /// # Errors
+    /// No intermediate value may overflow `T`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[2_i64, 3, 6], [4, 1, 2], [6, 8, 10]]);
+    /// let (u, h) = a.hermite_normal_form().expect("no overflow");
+    ///
+    /// assert_eq!(h, Matrix::from([[2, 0, 12], [0, 1, 8], [0, 0, 30]]));
+    /// assert_eq!(u * a, h);
+    /// ```
+    pub fn hermite_normal_form(&self) -> Result<(Matrix<T, R, R>, Self), OverflowError> {
+        let mut h = *self;
+        let mut u = Matrix::<T, R, R>::identity();
+        let mut row = 0;
+
+        for col in 0..C {
+            if row == R {
+                break;
+            }
+
+            for below in row + 1..R {
+                let (top, bottom) = (h[(row, col)], h[(below, col)]);
+
+                if bottom.is_zero() {
+                    continue;
+                }
+
+                let (gcd, x, y) = extended_gcd(top, bottom)?;
+                let combination = [
+                    [x, y],
+                    [
+                        checked_neg(checked_div(bottom, gcd)?)?,
+                        checked_div(top, gcd)?,
+                    ],
+                ];
+
+                h.combine_rows(row, below, combination)?;
+                u.combine_rows(row, below, combination)?;
+            }
+
+            let pivot = h[(row, col)];
+
+            if pivot.is_zero() {
+                continue;
+            }
+
+            if pivot.is_negative() {
+                h.negate_row(row)?;
+                u.negate_row(row)?;
+            }
+
+            for above in 0..row {
+                let quotient = floor_div(h[(above, col)], h[(row, col)]);
+
+                h.subtract_scaled_row(row, above, quotient)?;
+                u.subtract_scaled_row(row, above, quotient)?;
+            }
+
+            row += 1;
+        }
+
+        Ok((u, h))
+    }
+
+    /// Returns the Smith normal form `D` of the matrix and unimodular `U` and `V` with `UAV = D`,
+    /// or an error
+    ///
+    /// `D` is diagonal with non-negative invariant factors `d₀ | d₁ | ...`, which are unique for
+    /// each matrix.
+    ///
+    /// # Errors
+    /// No intermediate value may overflow `T`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[2_i64, 4, 4], [-6, 6, 12], [10, -4, -16]]);
+    /// let (u, d, v) = a.smith_normal_form().expect("no overflow");
+    ///
+    /// assert_eq!(d, Matrix::from([[2, 0, 0], [0, 6, 0], [0, 0, 12]]));
+    /// assert_eq!(u * a * v, d);
+    /// ```
+    pub fn smith_normal_form(
+        &self,
+    ) -> Result<(Matrix<T, R, R>, Self, Matrix<T, C, C>), OverflowError> {
+        let mut d = *self;
+        let mut u = Matrix::<T, R, R>::identity();
+        let mut v = Matrix::<T, C, C>::identity();
+
+        for t in 0..R.min(C) {
+            loop {
+                let Some((pivot_row, pivot_col)) = d.smallest_nonzero(t) else {
+                    return Ok((u, d, v));
+                };
+
+                d.swap_rows(t, pivot_row).unwrap_or_else(|_| unreachable!());
+                u.swap_rows(t, pivot_row).unwrap_or_else(|_| unreachable!());
+                d.swap_cols(t, pivot_col).unwrap_or_else(|_| unreachable!());
+                v.swap_cols(t, pivot_col).unwrap_or_else(|_| unreachable!());
+
+                let pivot = d[(t, t)];
+                let mut cleared = true;
+
+                for row in t + 1..R {
+                    let quotient = checked_div(d[(row, t)], pivot)?;
+
+                    d.subtract_scaled_row(t, row, quotient)?;
+                    u.subtract_scaled_row(t, row, quotient)?;
+                    cleared &= d[(row, t)].is_zero();
+                }
+
+                for col in t + 1..C {
+                    let quotient = checked_div(d[(t, col)], pivot)?;
+
+                    d.subtract_scaled_col(t, col, quotient)?;
+                    v.subtract_scaled_col(t, col, quotient)?;
+                    cleared &= d[(t, col)].is_zero();
+                }
+
+                if !cleared {
+                    continue;
+                }
+
+                let indivisible = (t + 1..R).find(|&row| {
+                    (t + 1..C).any(|col| {
+                        !checked_div(d[(row, col)], pivot)
+                            .is_ok_and(|quotient| quotient * pivot == d[(row, col)])
+                    })
+                });
+
+                let Some(row) = indivisible else {
+                    break;
+                };
+
+                d.subtract_scaled_row(row, t, T::zero() - T::one())?;
+                u.subtract_scaled_row(row, t, T::zero() - T::one())?;
+            }
+
+            if d[(t, t)].is_negative() {
+                d.negate_row(t)?;
+                u.negate_row(t)?;
+            }
+        }
+
+        Ok((u, d, v))
+    }
+
+    /// The position of the smallest magnitude non-zero element in rows and columns from `from`
+    fn smallest_nonzero(&self, from: usize) -> Option<(usize, usize)> {
+        (from..R)
+            .flat_map(|row| (from..C).map(move |col| (row, col)))
+            .filter(|&index| !self[index].is_zero())
+            .max_by_key(|&index| {
+                // Compare non-positive values, as the most negative value has no absolute value
+                let elem = self[index];
+                if elem.is_positive() {
+                    T::zero() - elem
+                } else {
+                    elem
+                }
+            })
+    }
+
+    /// Replaces rows `i` and `j` with the combinations of them given by the rows of `combination`
+    fn combine_rows(
+        &mut self,
+        i: usize,
+        j: usize,
+        combination: [[T; 2]; 2],
+    ) -> Result<(), OverflowError> {
+        for col in 0..C {
+            let (elem_i, elem_j) = (self[(i, col)], self[(j, col)]);
+            let [new_i, new_j] = combination.map(|[x, y]| {
+                x.checked_mul(&elem_i)
+                    .zip(y.checked_mul(&elem_j))
+                    .and_then(|(lhs, rhs)| lhs.checked_add(&rhs))
+            });
+
+            self[(i, col)] = new_i.ok_or(OverflowError)?;
+            self[(j, col)] = new_j.ok_or(OverflowError)?;
+        }
+
+        Ok(())
+    }
+
+    /// Subtracts `factor` times row `source` from row `target`
+    fn subtract_scaled_row(
+        &mut self,
+        source: usize,
+        target: usize,
+        factor: T,
+    ) -> Result<(), OverflowError> {
+        for col in 0..C {
+            self[(target, col)] = factor
+                .checked_mul(&self[(source, col)])
+                .and_then(|scaled| self[(target, col)].checked_sub(&scaled))
+                .ok_or(OverflowError)?;
+        }
+
+        Ok(())
+    }
+
+    /// Subtracts `factor` times column `source` from column `target`
+    fn subtract_scaled_col(
+        &mut self,
+        source: usize,
+        target: usize,
+        factor: T,
+    ) -> Result<(), OverflowError> {
+        for row in 0..R {
+            self[(row, target)] = factor
+                .checked_mul(&self[(row, source)])
+                .and_then(|scaled| self[(row, target)].checked_sub(&scaled))
+                .ok_or(OverflowError)?;
+        }
+
+        Ok(())
+    }
+
+    fn negate_row(&mut self, row: usize) -> Result<(), OverflowError> {
+        for col in 0..C {
+            self[(row, col)] = checked_neg(self[(row, col)])?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns `(g, x, y)` with `g = gcd(a, b) > 0` and `ax + by = g`, for `b` non-zero
+#[allow(clippy::many_single_char_names)]
+fn extended_gcd<T>(a: T, b: T) -> Result<(T, T, T), OverflowError>
+where
+    T: PrimInt + Signed,
+{
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+
+    while !r.is_zero() {
+        let quotient = checked_div(old_r, r)?;
+
+        (old_r, r) = (r, checked_sub_mul(old_r, quotient, r)?);
+        (old_x, x) = (x, checked_sub_mul(old_x, quotient, x)?);
+        (old_y, y) = (y, checked_sub_mul(old_y, quotient, y)?);
+    }
+
+    if old_r.is_negative() {
+        Ok((
+            checked_neg(old_r)?,
+            checked_neg(old_x)?,
+            checked_neg(old_y)?,
+        ))
+    } else {
+        Ok((old_r, old_x, old_y))
+    }
+}
+
+/// Returns `a - qb`
+fn checked_sub_mul<T: PrimInt>(a: T, q: T, b: T) -> Result<T, OverflowError> {
+    q.checked_mul(&b)
+        .and_then(|product| a.checked_sub(&product))
+        .ok_or(OverflowError)
+}
+
+fn checked_div<T: PrimInt>(a: T, b: T) -> Result<T, OverflowError> {
+    a.checked_div(&b).ok_or(OverflowError)
+}
+
+fn checked_neg<T: PrimInt>(a: T) -> Result<T, OverflowError> {
+    T::zero().checked_sub(&a).ok_or(OverflowError)
+}
+
+/// Returns `a / b` rounded towards negative infinity, for positive `b`
+fn floor_div<T: PrimInt + Signed>(a: T, b: T) -> T {
+    let quotient = a / b;
+
+    if (a % b).is_negative() {
+        quotient - T::one()
+    } else {
+        quotient
+    }
+}
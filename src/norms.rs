@@ -0,0 +1,153 @@
+use crate::Matrix;
+use num_traits::{Float, NumAssign};
+
+/// A matrix norm
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Norm {
+    /// The maximum absolute column sum, see [`Matrix::norm_1`]
+    One,
+    /// The largest singular value, see [`Matrix::norm_2`]
+    Two,
+    /// The maximum absolute row sum, see [`Matrix::norm_inf`]
+    Infinity,
+    /// The square root of the sum of squared elements, see [`Matrix::norm_frobenius`]
+    Frobenius,
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+    T: Float + NumAssign,
+{
+    /// Returns the given norm of the matrix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::{Matrix, Norm};
+    ///
+    /// let m = Matrix::from([[1.0, -2.0], [3.0, 4.0]]);
+    ///
+    /// assert_eq!(m.norm(Norm::One), 6.0);
+    /// assert_eq!(m.norm(Norm::Infinity), 7.0);
+    /// ```
+    pub fn norm(&self, norm: Norm) -> T {
+        match norm {
+            Norm::One => self.norm_1(),
+            Norm::Two => self.norm_2(),
+            Norm::Infinity => self.norm_inf(),
+            Norm::Frobenius => self.norm_frobenius(),
+        }
+    }
+
+    /// Returns the Frobenius norm, the square root of the sum of squared elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// assert_eq!(Matrix::from([[1.0, 2.0], [2.0, 4.0]]).norm_frobenius(), 5.0);
+    /// ```
+    pub fn norm_frobenius(&self) -> T {
+        self.iter().fold(T::zero(), |norm, elem| norm.hypot(*elem))
+    }
+
+    /// Returns the 1-norm, the maximum absolute column sum
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// assert_eq!(Matrix::from([[1.0, -2.0], [3.0, 4.0]]).norm_1(), 6.0);
+    /// ```
+    pub fn norm_1(&self) -> T {
+        (0..C).fold(T::zero(), |max, col| {
+            max.max((0..R).fold(T::zero(), |sum, row| sum + self[(row, col)].abs()))
+        })
+    }
+
+    /// Returns the infinity norm, the maximum absolute row sum
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// assert_eq!(Matrix::from([[1.0, -2.0], [3.0, 4.0]]).norm_inf(), 7.0);
+    /// ```
+    pub fn norm_inf(&self) -> T {
+        (0..R).fold(T::zero(), |max, row| {
+            max.max((0..C).fold(T::zero(), |sum, col| sum + self[(row, col)].abs()))
+        })
+    }
+
+    /// Returns the largest absolute element
+    ///
+    /// This is not a consistent matrix norm: `‖AB‖ ≤ ‖A‖‖B‖` does not always hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// assert_eq!(Matrix::from([[1.0, -5.0], [3.0, 4.0]]).norm_max(), 5.0);
+    /// ```
+    pub fn norm_max(&self) -> T {
+        self.iter().fold(T::zero(), |max, elem| max.max(elem.abs()))
+    }
+
+    /// Returns the spectral norm, the largest singular value
+    ///
+    /// NaN if the singular value decomposition does not converge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// assert!((Matrix::from([[3.0_f64, 0.0], [4.0, 5.0]]).norm_2() - 45.0_f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn norm_2(&self) -> T {
+        self.singular_values().map_or_else(
+            |_| T::nan(),
+            |values| values.first().copied().unwrap_or_else(T::zero),
+        )
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + NumAssign,
+{
+    /// Returns the condition number `‖A‖‖A⁻¹‖` in the given norm
+    ///
+    /// Singular matrices have an infinite condition number. In the 2-norm it is the ratio of the
+    /// extreme singular values, or NaN if the singular value decomposition does not converge.
+    /// Otherwise the inverse is computed explicitly,
+    /// see [`Lu::condition_number_estimate`](crate::Lu::condition_number_estimate) for a cheaper
+    /// estimate of the 1-norm condition number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::{Matrix, Norm};
+    ///
+    /// let m = Matrix::from([[1.0_f64, 0.0], [0.0, 1e-3]]);
+    ///
+    /// assert!((m.condition_number(Norm::Two) - 1e3).abs() < 1e-9);
+    /// assert_eq!(Matrix::from([[1.0, 1.0], [1.0, 1.0]]).condition_number(Norm::One), f64::INFINITY);
+    /// ```
+    pub fn condition_number(&self, norm: Norm) -> T {
+        if norm == Norm::Two {
+            return self
+                .svd()
+                .map_or_else(|_| T::nan(), |svd| svd.condition_number());
+        }
+
+        self.try_inverse().map_or_else(
+            |_| T::infinity(),
+            |inverse| self.norm(norm) * inverse.norm(norm),
+        )
+    }
+}
@@ -0,0 +1,135 @@
+use crate::Matrix;
+use num_traits::{CheckedAdd, CheckedMul, One, Zero};
+use std::ops::{Add, Mul};
+
+impl<T, const N: usize> Matrix<T, N, N> {
+    /// Returns the matrix raised to the power `exp`
+    ///
+    /// Computed by exponentiation by squaring in `O(log exp)` multiplications. Any matrix raised to
+    /// the power zero is the identity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let fibonacci = Matrix::from([[1_u64, 1], [1, 0]]);
+    ///
+    /// assert_eq!(fibonacci.pow(10), Matrix::from([[89, 55], [55, 34]]));
+    /// assert_eq!(fibonacci.pow(0), Matrix::identity());
+    /// ```
+    #[must_use]
+    pub fn pow(&self, mut exp: u32) -> Self
+    where
+        T: Mul<Output = T> + Add<Output = T> + Zero + One + Copy,
+    {
+        let mut result = Self::identity();
+        let mut base = *self;
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+
+            exp >>= 1;
+
+            if exp > 0 {
+                base = base * base;
+            }
+        }
+
+        result
+    }
+
+    /// Returns the matrix raised to the power `exp` or `None` if any element overflows
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let fibonacci = Matrix::from([[1_u8, 1], [1, 0]]);
+    ///
+    /// assert_eq!(fibonacci.checked_pow(12), Some(Matrix::from([[233, 144], [144, 89]])));
+    /// assert_eq!(fibonacci.checked_pow(13), None);
+    /// ```
+    #[must_use]
+    pub fn checked_pow(&self, mut exp: u32) -> Option<Self>
+    where
+        T: CheckedMul + CheckedAdd + Zero + One + Copy,
+    {
+        let mut result = Self::identity();
+        let mut base = *self;
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = checked_product(&result, &base)?;
+            }
+
+            exp >>= 1;
+
+            if exp > 0 {
+                base = checked_product(&base, &base)?;
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Returns `c₀I + c₁A + c₂A² + ...` for the coefficients `c` in increasing order of degree
+    ///
+    /// Evaluated with Horner's scheme in one multiplication per coefficient. An empty slice gives
+    /// the zero matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2], [3, 4]]);
+    ///
+    /// // The Cayley-Hamilton theorem: A² - 5A - 2I = 0
+    /// assert_eq!(a.eval_polynomial(&[-2, -5, 1]), Matrix::from([[0, 0], [0, 0]]));
+    /// assert_eq!(a.eval_polynomial(&[1, 1]), Matrix::from([[2, 2], [3, 5]]));
+    /// ```
+    #[must_use]
+    pub fn eval_polynomial(&self, coefficients: &[T]) -> Self
+    where
+        T: Mul<Output = T> + Add<Output = T> + Zero + Copy,
+    {
+        let mut result = Self::from_fn(|_, _| T::zero());
+
+        for (degree, &coefficient) in coefficients.iter().enumerate().rev() {
+            if degree + 1 < coefficients.len() {
+                result *= *self;
+            }
+
+            for i in 0..N {
+                result[(i, i)] = result[(i, i)] + coefficient;
+            }
+        }
+
+        result
+    }
+}
+
+/// Multiplies two matrices, returning `None` if any element overflows
+fn checked_product<T, const N: usize>(
+    lhs: &Matrix<T, N, N>,
+    rhs: &Matrix<T, N, N>,
+) -> Option<Matrix<T, N, N>>
+where
+    T: CheckedMul + CheckedAdd + Zero + Copy,
+{
+    let mut product = Matrix::from_fn(|_, _| T::zero());
+
+    for i in 0..N {
+        for j in 0..N {
+            product[(i, j)] = (0..N).try_fold(T::zero(), |acc, k| {
+                acc.checked_add(&lhs[(i, k)].checked_mul(&rhs[(k, j)])?)
+            })?;
+        }
+    }
+
+    Some(product)
+}
@@ -0,0 +1,92 @@
+use crate::Matrix;
+use std::ops::Mul;
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+    T: Mul<Output = T> + Copy,
+{
+    /// Returns the Kronecker product `A ⊗ B`, the block matrix whose `(i, j)` block is `aᵢⱼB`
+    ///
+    /// The output dimensions `RO` and `CO` are checked at compile time to be `R * R2` and
+    /// `C * C2`, and can usually be inferred from the binding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2], [3, 4]]);
+    /// let b = Matrix::from([[0, 5], [6, 7]]);
+    /// let product: Matrix<i32, 4, 4> = a.kronecker(&b);
+    ///
+    /// assert_eq!(
+    ///     product,
+    ///     Matrix::from([
+    ///         [0, 5, 0, 10],
+    ///         [6, 7, 12, 14],
+    ///         [0, 15, 0, 20],
+    ///         [18, 21, 24, 28],
+    ///     ])
+    /// );
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2], [3, 4]]);
+    /// let _: Matrix<i32, 4, 3> = a.kronecker(&a);
+    /// ```
+    #[must_use]
+    pub fn kronecker<const R2: usize, const C2: usize, const RO: usize, const CO: usize>(
+        &self,
+        rhs: &Matrix<T, R2, C2>,
+    ) -> Matrix<T, RO, CO> {
+        const {
+            assert!(RO == R * R2, "the output must have R * R2 rows");
+            assert!(CO == C * C2, "the output must have C * C2 columns");
+        }
+
+        Matrix::from_fn(|row, col| self[(row / R2, col / C2)] * rhs[(row % R2, col % C2)])
+    }
+
+    /// Returns the Hadamard product, the elementwise product of the two matrices
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// let b = Matrix::from([[2, 0, -1], [1, 3, 2]]);
+    ///
+    /// assert_eq!(a.hadamard(&b), Matrix::from([[2, 0, -3], [4, 15, 12]]));
+    /// ```
+    #[must_use]
+    pub fn hadamard(&self, rhs: &Self) -> Self {
+        Self::from_fn(|row, col| self[(row, col)] * rhs[(row, col)])
+    }
+
+    /// Returns the elementwise product of the two matrices, the same as [`Matrix::hadamard`]
+    #[must_use]
+    pub fn component_mul(&self, rhs: &Self) -> Self {
+        self.hadamard(rhs)
+    }
+
+    /// Returns the outer product `xyᵀ` of a column vector and a row vector
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let col = Matrix::from([[1], [2], [3]]);
+    /// let row = Matrix::from([[4, 5]]);
+    ///
+    /// assert_eq!(Matrix::outer(&col, &row), Matrix::from([[4, 5], [8, 10], [12, 15]]));
+    /// assert_eq!(Matrix::outer(&col, &row), col * row);
+    /// ```
+    #[must_use]
+    pub fn outer(col: &Matrix<T, R, 1>, row: &Matrix<T, 1, C>) -> Self {
+        Self::from_fn(|i, j| col[(i, 0)] * row[(0, j)])
+    }
+}
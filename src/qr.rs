@@ -0,0 +1,143 @@
+use crate::{errors::SvdError, Householder, Matrix};
+use num_traits::{Float, NumAssign};
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+    T: Float + NumAssign,
+{
+    /// Returns the QR decomposition of the matrix, `A = QR`
+    ///
+    /// `Q` is orthogonal and `R` is upper triangular. Computed with Householder reflections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[12.0, -51.0, 4.0], [6.0, 167.0, -68.0], [-4.0, 24.0, -41.0]]);
+    /// let (q, r) = a.qr();
+    ///
+    /// let error = q * r - a;
+    /// let orthogonality = q.to_transpose() * q - Matrix::identity();
+    ///
+    /// assert!(error.iter().all(|elem: &f64| elem.abs() < 1e-12));
+    /// assert!(orthogonality.iter().all(|elem: &f64| elem.abs() < 1e-12));
+    /// assert!(r[(1, 0)] == 0.0 && r[(2, 0)] == 0.0 && r[(2, 1)] == 0.0);
+    /// ```
+    #[must_use]
+    pub fn qr(&self) -> (Matrix<T, R, R>, Self) {
+        let mut q = Matrix::<T, R, R>::identity();
+        let mut r = *self;
+
+        for col in 0..C.min(R.saturating_sub(1)) {
+            let reflection = Householder::new_at(&Matrix::from_fn(|row, _| r[(row, col)]), col)
+                .unwrap_or_else(|_| unreachable!());
+
+            reflection.apply_left(&mut r);
+            reflection.apply_right(&mut q);
+
+            r[(col, col)] = reflection.alpha();
+            for row in col + 1..R {
+                r[(row, col)] = T::zero();
+            }
+        }
+
+        (q, r)
+    }
+
+    /// Returns the thin QR decomposition of a matrix with at least as many rows as columns
+    ///
+    /// `Q` has orthonormal columns and `R` is square and upper triangular. Fails to compile if the
+    /// matrix has fewer rows than columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    /// let (q, r) = a.thin_qr();
+    ///
+    /// let error = q * r - a;
+    /// let orthogonality = q.to_transpose() * q - Matrix::identity();
+    ///
+    /// assert!(error.iter().all(|elem: &f64| elem.abs() < 1e-12));
+    /// assert!(orthogonality.iter().all(|elem: &f64| elem.abs() < 1e-12));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use matrixes::Matrix;
+    ///
+    /// let _ = Matrix::<f64, 2, 3>::new().thin_qr();
+    /// ```
+    #[must_use]
+    pub fn thin_qr(&self) -> (Self, Matrix<T, C, C>) {
+        const {
+            assert!(R >= C, "thin QR needs at least as many rows as columns");
+        }
+
+        let (q, r) = self.qr();
+
+        (
+            Self::from_fn(|row, col| q[(row, col)]),
+            Matrix::from_fn(|row, col| r[(row, col)]),
+        )
+    }
+
+    /// Returns the `x` minimising `‖Ax - b‖` for every column of `b`
+    ///
+    /// Matrices with full column rank are solved through the QR decomposition. Otherwise the
+    /// minimum norm solution is found through the singular value decomposition, treating singular
+    /// values within rounding error of zero as zero.
+    ///
+    /// # Errors
+    /// The singular value decomposition must converge when it is needed, see [`Matrix::svd`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// // Fit y = c₀ + c₁t through (0, 1), (1, 3), (2, 4) and (3, 8)
+    /// let a = Matrix::from([[1.0_f64, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+    /// let b = Matrix::from([[1.0], [3.0], [4.0], [8.0]]);
+    /// let x = a.least_squares(&b).expect("jacobi converges");
+    ///
+    /// assert!((x[(0, 0)] - 0.7).abs() < 1e-12);
+    /// assert!((x[(1, 0)] - 2.2).abs() < 1e-12);
+    /// ```
+    pub fn least_squares<const K: usize>(
+        &self,
+        b: &Matrix<T, R, K>,
+    ) -> Result<Matrix<T, C, K>, SvdError> {
+        let (q, r) = self.qr();
+        let largest = (0..C.min(R)).fold(T::zero(), |acc, i| acc.max(r[(i, i)].abs()));
+        let tol = T::epsilon() * T::from(R.max(C)).unwrap_or_else(T::one) * largest;
+
+        if R < C || (0..C).any(|i| r[(i, i)].abs() <= tol) {
+            let svd = self.svd()?;
+            let largest = svd
+                .singular_values()
+                .first()
+                .copied()
+                .unwrap_or_else(T::zero);
+
+            return Ok(svd.pseudo_inverse(
+                T::epsilon() * T::from(R.max(C)).unwrap_or_else(T::one) * largest,
+            ) * *b);
+        }
+
+        let qt_b = q.to_transpose() * *b;
+        let mut x = Matrix::<T, C, K>::of_elem(T::zero());
+
+        for col in 0..K {
+            for row in (0..C).rev() {
+                let sum =
+                    (row + 1..C).fold(qt_b[(row, col)], |acc, k| acc - r[(row, k)] * x[(k, col)]);
+                x[(row, col)] = sum / r[(row, row)];
+            }
+        }
+
+        Ok(x)
+    }
+}
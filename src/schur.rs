@@ -0,0 +1,323 @@
+use crate::{errors::EigenError, Givens, Householder, Matrix};
+use num_complex::Complex;
+use num_traits::{Float, NumAssign};
+
+/// A real Schur form `A = QTQᵀ` along with the eigenvalues read off its diagonal blocks
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) struct RealSchur<T, const N: usize> {
+    pub(crate) q: Matrix<T, N, N>,
+    pub(crate) t: Matrix<T, N, N>,
+    pub(crate) eigenvalues: [Complex<T>; N],
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + NumAssign,
+{
+    /// Returns the upper Hessenberg form of the matrix, `(Q, H)` such that `A = QHQᵀ`
+    ///
+    /// `Q` is orthogonal and every element of `H` below the first subdiagonal is zero. Computed
+    /// with Householder reflections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([
+    ///     [4.0_f64, 1.0, -2.0, 2.0],
+    ///     [1.0, 2.0, 0.0, 1.0],
+    ///     [-2.0, 0.0, 3.0, -2.0],
+    ///     [2.0, 1.0, -2.0, -1.0],
+    /// ]);
+    /// let (q, h) = a.hessenberg();
+    ///
+    /// assert_eq!((h[(2, 0)], h[(3, 0)], h[(3, 1)]), (0.0, 0.0, 0.0));
+    /// assert!((q * h * q.to_transpose() - a).iter().all(|elem| elem.abs() < 1e-12));
+    /// ```
+    #[must_use]
+    pub fn hessenberg(&self) -> (Self, Self) {
+        let mut h = *self;
+        let mut q = Self::identity();
+
+        for col in 0..N.saturating_sub(2) {
+            let reflection = Householder::new_at(&Matrix::from_fn(|row, _| h[(row, col)]), col + 1)
+                .unwrap_or_else(|_| unreachable!());
+
+            reflection.apply_left(&mut h);
+            reflection.apply_right(&mut h);
+            reflection.apply_right(&mut q);
+
+            h[(col + 1, col)] = reflection.alpha();
+            for row in col + 2..N {
+                h[(row, col)] = T::zero();
+            }
+        }
+
+        (q, h)
+    }
+
+    /// Returns the real Schur form of the matrix, `(Q, T)` such that `A = QTQᵀ`, or an error
+    ///
+    /// `Q` is orthogonal and `T` is upper quasi-triangular: real eigenvalues sit on its diagonal
+    /// and each complex conjugate pair is a 2×2 block on the diagonal, with every other element
+    /// below the diagonal zero.
+    ///
+    /// # Errors
+    /// The QR iteration must converge within `30 * max(N, 10)` steps per eigenvalue
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[1.0_f64, 2.0, 0.0], [-2.0, 1.0, 3.0], [0.0, 0.0, 4.0]]);
+    /// let (q, t) = a.schur().expect("QR iteration converges");
+    ///
+    /// assert_eq!((t[(2, 0)], t[(2, 1)]), (0.0, 0.0));
+    /// assert!((q * t * q.to_transpose() - a).iter().all(|elem| elem.abs() < 1e-12));
+    /// ```
+    pub fn schur(&self) -> Result<(Self, Self), EigenError> {
+        let RealSchur { q, t, .. } = self.real_schur()?;
+
+        Ok((q, t))
+    }
+
+    /// Computes the real Schur form with Francis double-shift QR steps on the Hessenberg form
+    ///
+    /// Follows `hqr2` from EISPACK: the 2×2 blocks left on the diagonal hold complex conjugate
+    /// eigenvalue pairs and blocks with real eigenvalues are split by a rotation.
+    #[allow(clippy::many_single_char_names, clippy::too_many_lines)]
+    pub(crate) fn real_schur(&self) -> Result<RealSchur<T, N>, EigenError> {
+        let max_iter = 30 * N.max(10);
+        let (mut u, mut h) = self.hessenberg();
+        let mut eigenvalues = [Complex::new(T::zero(), T::zero()); N];
+
+        let eps = T::epsilon();
+        let two = T::from(2).unwrap_or_else(T::one);
+        let norm = h.iter().fold(T::zero(), |acc, elem| acc + elem.abs());
+        let mut exshift = T::zero();
+        let mut iter = 0;
+        let mut end = N;
+
+        while end > 0 {
+            let n = end - 1;
+
+            let mut l = n;
+            while l > 0 {
+                let mut s = h[(l - 1, l - 1)].abs() + h[(l, l)].abs();
+                if s.is_zero() {
+                    s = norm;
+                }
+                if h[(l, l - 1)].abs() <= eps * s {
+                    break;
+                }
+                l -= 1;
+            }
+
+            if l == n {
+                h[(n, n)] += exshift;
+                eigenvalues[n] = Complex::new(h[(n, n)], T::zero());
+
+                if n > 0 {
+                    h[(n, n - 1)] = T::zero();
+                }
+
+                end = n;
+                iter = 0;
+            } else if l + 1 == n {
+                let w = h[(n, n - 1)] * h[(n - 1, n)];
+                let p = (h[(n - 1, n - 1)] - h[(n, n)]) / two;
+                let q = p * p + w;
+                let mut z = q.abs().sqrt();
+                h[(n, n)] += exshift;
+                h[(n - 1, n - 1)] += exshift;
+                let x = h[(n, n)];
+
+                if n > 1 {
+                    h[(n - 1, n - 2)] = T::zero();
+                }
+
+                if q >= T::zero() {
+                    z = if p >= T::zero() { p + z } else { p - z };
+                    let upper = x + z;
+                    let lower = if z.is_zero() { upper } else { x - w / z };
+                    eigenvalues[n - 1] = Complex::new(upper, T::zero());
+                    eigenvalues[n] = Complex::new(lower, T::zero());
+
+                    // The block is isolated, rows n - 1 and n are zero left of it and columns
+                    // n - 1 and n are zero below it, so rotating them whole only splits the block
+                    let rotation = Givens::new(z, h[(n, n - 1)]);
+                    rotation
+                        .apply_to_rows(&mut h, n - 1, n)
+                        .unwrap_or_else(|_| unreachable!());
+                    rotation
+                        .apply_to_cols(&mut h, n - 1, n)
+                        .unwrap_or_else(|_| unreachable!());
+                    rotation
+                        .apply_to_cols(&mut u, n - 1, n)
+                        .unwrap_or_else(|_| unreachable!());
+
+                    h[(n, n - 1)] = T::zero();
+                } else {
+                    eigenvalues[n - 1] = Complex::new(x + p, z);
+                    eigenvalues[n] = Complex::new(x + p, -z);
+                }
+
+                end = n - 1;
+                iter = 0;
+            } else {
+                if iter == max_iter {
+                    return Err(EigenError::NoConvergence {
+                        iterations: max_iter,
+                    });
+                }
+
+                let mut x = h[(n, n)];
+                let mut y = h[(n - 1, n - 1)];
+                let mut w = h[(n, n - 1)] * h[(n - 1, n)];
+
+                if iter == 10 {
+                    exshift += x;
+                    for i in 0..=n {
+                        h[(i, i)] -= x;
+                    }
+                    let s = h[(n, n - 1)].abs() + h[(n - 1, n - 2)].abs();
+                    x = T::from(0.75).unwrap_or_else(T::one) * s;
+                    y = x;
+                    w = T::from(-0.4375).unwrap_or_else(T::one) * s * s;
+                }
+
+                if iter == 30 {
+                    let s = (y - x) / two;
+                    let s = s * s + w;
+                    if s > T::zero() {
+                        let s = s.sqrt().copysign(y - x);
+                        let s = x - w / ((y - x) / two + s);
+                        for i in 0..=n {
+                            h[(i, i)] -= s;
+                        }
+                        exshift += s;
+                        x = T::from(0.964).unwrap_or_else(T::one);
+                        y = x;
+                        w = x;
+                    }
+                }
+
+                iter += 1;
+
+                let mut m = n - 2;
+                let (mut p, mut q, mut r);
+                loop {
+                    let z = h[(m, m)];
+                    let (rr, ss) = (x - z, y - z);
+                    p = (rr * ss - w) / h[(m + 1, m)] + h[(m, m + 1)];
+                    q = h[(m + 1, m + 1)] - z - rr - ss;
+                    r = h[(m + 2, m + 1)];
+                    let s = p.abs() + q.abs() + r.abs();
+                    p /= s;
+                    q /= s;
+                    r /= s;
+
+                    if m == l
+                        || h[(m, m - 1)].abs() * (q.abs() + r.abs())
+                            < eps
+                                * (p.abs()
+                                    * (h[(m - 1, m - 1)].abs() + z.abs() + h[(m + 1, m + 1)].abs()))
+                    {
+                        break;
+                    }
+                    m -= 1;
+                }
+
+                for i in m + 2..=n {
+                    h[(i, i - 2)] = T::zero();
+                    if i > m + 2 {
+                        h[(i, i - 3)] = T::zero();
+                    }
+                }
+
+                for k in m..n {
+                    let notlast = k != n - 1;
+
+                    if k != m {
+                        p = h[(k, k - 1)];
+                        q = h[(k + 1, k - 1)];
+                        r = if notlast {
+                            h[(k + 2, k - 1)]
+                        } else {
+                            T::zero()
+                        };
+                        x = p.abs() + q.abs() + r.abs();
+                        if x.is_zero() {
+                            continue;
+                        }
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+
+                    let s = (p * p + q * q + r * r).sqrt().copysign(p);
+                    if s.is_zero() {
+                        continue;
+                    }
+
+                    if k != m {
+                        h[(k, k - 1)] = -s * x;
+                    } else if l != m {
+                        h[(k, k - 1)] = -h[(k, k - 1)];
+                    }
+
+                    p += s;
+                    x = p / s;
+                    y = q / s;
+                    let z = r / s;
+                    q /= p;
+                    r /= p;
+
+                    for j in k..N {
+                        let mut p = h[(k, j)] + q * h[(k + 1, j)];
+                        if notlast {
+                            p += r * h[(k + 2, j)];
+                            h[(k + 2, j)] -= p * z;
+                        }
+                        h[(k, j)] -= p * x;
+                        h[(k + 1, j)] -= p * y;
+                    }
+
+                    for i in 0..=n.min(k + 3) {
+                        let mut p = x * h[(i, k)] + y * h[(i, k + 1)];
+                        if notlast {
+                            p += z * h[(i, k + 2)];
+                            h[(i, k + 2)] -= p * r;
+                        }
+                        h[(i, k)] -= p;
+                        h[(i, k + 1)] -= p * q;
+                    }
+
+                    for i in 0..N {
+                        let mut p = x * u[(i, k)] + y * u[(i, k + 1)];
+                        if notlast {
+                            p += z * u[(i, k + 2)];
+                            u[(i, k + 2)] -= p * r;
+                        }
+                        u[(i, k)] -= p;
+                        u[(i, k + 1)] -= p * q;
+                    }
+                }
+            }
+        }
+
+        for row in 0..N {
+            for col in 0..row.saturating_sub(1) {
+                h[(row, col)] = T::zero();
+            }
+        }
+
+        Ok(RealSchur {
+            q: u,
+            t: h,
+            eigenvalues,
+        })
+    }
+}
@@ -0,0 +1,337 @@
+use crate::{errors::SvdError, Givens, Matrix};
+use num_traits::{Float, NumAssign};
+use std::{array, cmp::Ordering};
+
+const MAX_SWEEPS: usize = 64;
+
+/// A singular value decomposition, `A = UΣVᵀ`
+///
+/// `U` and `V` are orthogonal and `Σ` is diagonal with non-negative singular values in decreasing
+/// order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Svd<T, const R: usize, const C: usize> {
+    u: Matrix<T, R, R>,
+    singular_values: Vec<T>,
+    v_t: Matrix<T, C, C>,
+}
+
+impl<T, const R: usize, const C: usize> Svd<T, R, C>
+where
+    T: Float + NumAssign,
+{
+    /// Decomposes a matrix with at least as many rows as columns by one-sided Jacobi rotations
+    fn one_sided_jacobi(matrix: &Matrix<T, R, C>) -> Result<Self, SvdError> {
+        debug_assert!(R >= C, "one-sided Jacobi is applied to tall matrices");
+
+        // Working on the matrix scaled to unit largest element keeps the sums of squares below
+        // from overflowing or underflowing, the singular values are scaled back at the end
+        let scale = matrix
+            .iter()
+            .fold(T::zero(), |max, elem| max.max(elem.abs()));
+        let scale = if scale.is_zero() || !scale.is_finite() {
+            T::one()
+        } else {
+            scale
+        };
+
+        let mut w = matrix.map(|elem| elem / scale);
+        let mut v = Matrix::<T, C, C>::identity();
+        let mut converged = false;
+
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+
+            for p in 0..C {
+                for q in p + 1..C {
+                    let (alpha, beta, gamma) =
+                        (0..R).fold((T::zero(), T::zero(), T::zero()), |(a, b, g), row| {
+                            let (wp, wq) = (w[(row, p)], w[(row, q)]);
+                            (a + wp * wp, b + wq * wq, g + wp * wq)
+                        });
+
+                    if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() || gamma.is_zero() {
+                        continue;
+                    }
+
+                    rotated = true;
+
+                    let zeta = (beta - alpha) / (T::from(2).unwrap_or_else(T::one) * gamma);
+                    let t = T::one().copysign(zeta) / (zeta.abs() + zeta.hypot(T::one()));
+                    let rotation = Givens::new(T::one(), -t);
+
+                    rotation
+                        .apply_to_cols(&mut w, p, q)
+                        .unwrap_or_else(|_| unreachable!());
+                    rotation
+                        .apply_to_cols(&mut v, p, q)
+                        .unwrap_or_else(|_| unreachable!());
+                }
+            }
+
+            if !rotated {
+                converged = true;
+                break;
+            }
+        }
+
+        if !converged {
+            return Err(SvdError::NoConvergence { sweeps: MAX_SWEEPS });
+        }
+
+        let norms: [T; C] =
+            array::from_fn(|col| (0..R).fold(T::zero(), |norm, row| norm.hypot(w[(row, col)])));
+        let mut order: [usize; C] = array::from_fn(|col| col);
+        order.sort_by(|&lhs, &rhs| {
+            norms[rhs]
+                .partial_cmp(&norms[lhs])
+                .unwrap_or(Ordering::Equal)
+        });
+
+        let largest = order.first().map_or_else(T::zero, |&col| norms[col]);
+        let negligible = largest * T::epsilon() * T::from(R).unwrap_or_else(T::one);
+
+        let mut u = Matrix::<T, R, R>::of_elem(T::zero());
+        let mut filled = 0;
+
+        for &col in order.iter().take_while(|&&col| norms[col] > negligible) {
+            for row in 0..R {
+                u[(row, filled)] = w[(row, col)] / norms[col];
+            }
+
+            filled += 1;
+        }
+
+        complete_basis(&mut u, filled);
+
+        Ok(Self {
+            u,
+            singular_values: order.iter().map(|&col| norms[col] * scale).collect(),
+            v_t: Matrix::from_fn(|row, col| v[(col, order[row])]),
+        })
+    }
+
+    /// Returns the left singular vectors `U` as columns
+    pub const fn u(&self) -> &Matrix<T, R, R> {
+        &self.u
+    }
+
+    /// Returns the transpose of the right singular vectors, `Vᵀ`
+    pub const fn v_t(&self) -> &Matrix<T, C, C> {
+        &self.v_t
+    }
+
+    /// Returns the singular values in decreasing order
+    ///
+    /// There are as many singular values as the smaller dimension of the matrix.
+    pub fn singular_values(&self) -> &[T] {
+        &self.singular_values
+    }
+
+    /// Returns the diagonal matrix of singular values, `Σ`
+    pub fn sigma(&self) -> Matrix<T, R, C> {
+        Matrix::from_fn(|row, col| {
+            if row == col {
+                self.singular_values[row]
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Returns the number of singular values greater than `tol`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let svd = Matrix::from([[1.0, 0.0], [0.0, 1e-10]]).svd().expect("jacobi converges");
+    ///
+    /// assert_eq!(svd.rank_with_tol(1e-12), 2);
+    /// assert_eq!(svd.rank_with_tol(1e-8), 1);
+    /// ```
+    pub fn rank_with_tol(&self, tol: T) -> usize {
+        self.singular_values
+            .iter()
+            .take_while(|&&value| value > tol)
+            .count()
+    }
+
+    /// Returns the Moore-Penrose pseudo-inverse, `VΣ⁺Uᵀ`
+    ///
+    /// Singular values of at most `tol` are treated as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[1.0, 1.0], [1.0, 1.0], [0.0, 0.0]]);
+    /// let pinv = a.svd().expect("jacobi converges").pseudo_inverse(1e-12);
+    /// let expected = Matrix::from([[0.25, 0.25, 0.0], [0.25, 0.25, 0.0]]);
+    ///
+    /// assert!((pinv - expected).iter().all(|elem: &f64| elem.abs() < 1e-12));
+    /// ```
+    pub fn pseudo_inverse(&self, tol: T) -> Matrix<T, C, R> {
+        let rank = self.rank_with_tol(tol);
+
+        Matrix::from_fn(|row, col| {
+            (0..rank).fold(T::zero(), |acc, k| {
+                acc + self.v_t[(k, row)] * self.u[(col, k)] / self.singular_values[k]
+            })
+        })
+    }
+
+    /// Returns the 2-norm condition number, the ratio of the largest to the smallest singular value
+    ///
+    /// Singular matrices have an infinite condition number and empty matrices have a condition
+    /// number of one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let svd = Matrix::from([[2.0, 0.0], [0.0, 0.5]]).svd().expect("jacobi converges");
+    /// let singular = Matrix::from([[1.0, 1.0], [1.0, 1.0]]).svd().expect("jacobi converges");
+    ///
+    /// assert_eq!(svd.condition_number(), 4.0);
+    /// assert_eq!(singular.condition_number(), f64::INFINITY);
+    /// ```
+    pub fn condition_number(&self) -> T {
+        match (self.singular_values.first(), self.singular_values.last()) {
+            (Some(&largest), Some(&smallest)) if smallest > T::zero() => largest / smallest,
+            (Some(_), Some(_)) => T::infinity(),
+            _ => T::one(),
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+    T: Float + NumAssign,
+{
+    /// Returns the singular value decomposition of the matrix
+    ///
+    /// Computed with one-sided Jacobi rotations, which give singular values to high relative
+    /// accuracy.
+    ///
+    /// # Errors
+    /// The Jacobi rotations must converge within 64 sweeps
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[3.0_f64, 2.0, 2.0], [2.0, 3.0, -2.0]]);
+    /// let svd = a.svd().expect("jacobi converges");
+    ///
+    /// let error = svd.u() * svd.sigma() * svd.v_t() - a;
+    ///
+    /// assert!(error.iter().all(|elem: &f64| elem.abs() < 1e-12));
+    /// assert!((svd.singular_values()[0] - 5.0).abs() < 1e-12);
+    /// assert!((svd.singular_values()[1] - 3.0).abs() < 1e-12);
+    /// ```
+    pub fn svd(&self) -> Result<Svd<T, R, C>, SvdError> {
+        if R >= C {
+            return Svd::one_sided_jacobi(self);
+        }
+
+        let Svd {
+            u,
+            singular_values,
+            v_t,
+        } = Svd::one_sided_jacobi(&self.to_transpose())?;
+
+        Ok(Svd {
+            u: v_t.to_transpose(),
+            singular_values,
+            v_t: u.to_transpose(),
+        })
+    }
+
+    /// Returns the Moore-Penrose pseudo-inverse of the matrix
+    ///
+    /// Computed from the singular value decomposition, treating singular values of at most `tol`
+    /// as zero. See [`Svd::pseudo_inverse`] to reuse a decomposition.
+    ///
+    /// # Errors
+    /// The singular value decomposition must converge, see [`Matrix::svd`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let a = Matrix::from([[1.0, 2.0], [2.0, 4.0], [0.0, 0.0]]);
+    /// let pinv = a.pseudo_inverse(1e-12).expect("jacobi converges");
+    ///
+    /// assert!((a * pinv * a - a).iter().all(|elem: &f64| elem.abs() < 1e-12));
+    /// assert!((pinv * a * pinv - pinv).iter().all(|elem: &f64| elem.abs() < 1e-12));
+    /// ```
+    pub fn pseudo_inverse(&self, tol: T) -> Result<Matrix<T, C, R>, SvdError> {
+        Ok(self.svd()?.pseudo_inverse(tol))
+    }
+
+    /// Returns the singular values of the matrix in decreasing order
+    ///
+    /// # Errors
+    /// The singular value decomposition must converge, see [`Matrix::svd`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::Matrix;
+    ///
+    /// let m = Matrix::from([[0.0, -2.0], [1.0, 0.0], [0.0, 0.0]]);
+    ///
+    /// assert_eq!(m.singular_values(), Ok(vec![2.0, 1.0]));
+    /// ```
+    pub fn singular_values(&self) -> Result<Vec<T>, SvdError> {
+        Ok(self.svd()?.singular_values)
+    }
+}
+
+/// Fills the columns of `u` after the first `filled` orthonormal ones to make it orthogonal
+///
+/// Each new column starts from the standard basis vector furthest from the columns so far.
+fn complete_basis<T, const N: usize>(u: &mut Matrix<T, N, N>, filled: usize)
+where
+    T: Float + NumAssign,
+{
+    let mut row_norms: [T; N] =
+        array::from_fn(|row| (0..filled).fold(T::zero(), |acc, col| acc + u[(row, col)].powi(2)));
+
+    for col in filled..N {
+        let start = (0..N).fold(0, |best, row| {
+            if row_norms[row] < row_norms[best] {
+                row
+            } else {
+                best
+            }
+        });
+
+        for row in 0..N {
+            u[(row, col)] = if row == start { T::one() } else { T::zero() };
+        }
+
+        for _ in 0..2 {
+            for prev in 0..col {
+                let dot = (0..N).fold(T::zero(), |acc, row| acc + u[(row, prev)] * u[(row, col)]);
+
+                for row in 0..N {
+                    let elem = u[(row, prev)];
+                    u[(row, col)] -= dot * elem;
+                }
+            }
+        }
+
+        let norm = (0..N).fold(T::zero(), |norm, row| norm.hypot(u[(row, col)]));
+
+        for (row, row_norm) in row_norms.iter_mut().enumerate() {
+            u[(row, col)] /= norm;
+            *row_norm += u[(row, col)].powi(2);
+        }
+    }
+}
@@ -1,6 +1,277 @@
 use crate::{errors::IndexError, Matrix};
+use num_traits::{Float, NumAssign};
 use std::{array, mem};
 
+/// A Householder reflection, `I - βvvᵀ`
+///
+/// Reflects a vector onto a multiple of a standard basis vector, zeroing every element after it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Householder<T, const N: usize> {
+    v: [T; N],
+    beta: T,
+    alpha: T,
+    start: usize,
+}
+
+impl<T, const N: usize> Householder<T, N>
+where
+    T: Float + NumAssign,
+{
+    /// Returns the reflection taking `x` to `αe₀`, where `|α| = ‖x‖`
+    ///
+    /// The sign of `α` is chosen opposite to the first element of `x` to avoid cancellation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::{Householder, Matrix};
+    ///
+    /// let mut x = Matrix::from([[3.0_f64], [0.0], [4.0]]);
+    /// let reflection = Householder::new(&x);
+    /// reflection.apply_left(&mut x);
+    ///
+    /// assert_eq!(reflection.alpha(), -5.0);
+    /// assert!((x - Matrix::from([[-5.0], [0.0], [0.0]])).iter().all(|elem| elem.abs() < 1e-12));
+    /// ```
+    #[must_use]
+    pub fn new(x: &Matrix<T, N, 1>) -> Self {
+        Self::new_at(x, 0).unwrap_or_else(|_| Self {
+            v: [T::zero(); N],
+            beta: T::zero(),
+            alpha: T::zero(),
+            start: 0,
+        })
+    }
+
+    /// Returns the reflection taking `x` to a vector that agrees with it before `start`, has
+    /// magnitude `‖x[start..]‖` at `start` and is zero after it
+    ///
+    /// The reflection leaves the elements before `start` of any vector it is applied to unchanged.
+    ///
+    /// # Errors
+    /// start must index within bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::{Householder, Matrix};
+    ///
+    /// let mut a = Matrix::from([[1.0_f64, 2.0], [-3.0, 0.0], [0.0, 4.0], [4.0, 1.0]]);
+    /// let first_col = Matrix::from_fn(|row, _| a[(row, 0)]);
+    /// let reflection = Householder::new_at(&first_col, 1).expect("start is within bounds");
+    /// reflection.apply_left(&mut a);
+    ///
+    /// assert_eq!(a[(0, 0)], 1.0);
+    /// assert!((a[(1, 0)] - 5.0).abs() < 1e-12);
+    /// assert!(a[(2, 0)].abs() < 1e-12 && a[(3, 0)].abs() < 1e-12);
+    /// ```
+    pub fn new_at(x: &Matrix<T, N, 1>, start: usize) -> Result<Self, IndexError> {
+        if start >= N {
+            return Err(IndexError::Row(start));
+        }
+
+        let mut v: [T; N] = array::from_fn(|row| if row < start { T::zero() } else { x[(row, 0)] });
+        let norm = v.iter().fold(T::zero(), |norm, elem| norm.hypot(*elem));
+
+        if norm.is_zero() {
+            return Ok(Self {
+                v,
+                beta: T::zero(),
+                alpha: T::zero(),
+                start,
+            });
+        }
+
+        // Scaling v so that v[start] is one keeps β = 2 / vᵀv within [1, 2], where squaring the
+        // elements of x directly would overflow or underflow at extreme magnitudes
+        let alpha = -norm.copysign(v[start]);
+        let head = v[start] - alpha;
+        v[start] = T::one();
+        for elem in &mut v[start + 1..] {
+            *elem /= head;
+        }
+
+        Ok(Self {
+            v,
+            beta: -head / alpha,
+            alpha,
+            start,
+        })
+    }
+
+    /// Returns the element at `start` of the reflected vector, `±‖x[start..]‖`
+    pub const fn alpha(&self) -> T {
+        self.alpha
+    }
+
+    /// Returns the reflection as a matrix
+    #[must_use]
+    pub fn to_matrix(&self) -> Matrix<T, N, N> {
+        let mut matrix = Matrix::identity();
+        self.apply_left(&mut matrix);
+        matrix
+    }
+
+    /// Replaces `matrix` with `HA`, reflecting each of its columns
+    pub fn apply_left<const C: usize>(&self, matrix: &mut Matrix<T, N, C>) {
+        if self.beta.is_zero() {
+            return;
+        }
+
+        for col in 0..C {
+            let dot = (self.start..N)
+                .fold(T::zero(), |acc, row| acc + self.v[row] * matrix[(row, col)])
+                * self.beta;
+
+            for row in self.start..N {
+                matrix[(row, col)] -= dot * self.v[row];
+            }
+        }
+    }
+
+    /// Replaces `matrix` with `AH`, reflecting each of its rows
+    pub fn apply_right<const R: usize>(&self, matrix: &mut Matrix<T, R, N>) {
+        if self.beta.is_zero() {
+            return;
+        }
+
+        for row in 0..R {
+            let dot = (self.start..N)
+                .fold(T::zero(), |acc, col| acc + matrix[(row, col)] * self.v[col])
+                * self.beta;
+
+            for col in self.start..N {
+                matrix[(row, col)] -= dot * self.v[col];
+            }
+        }
+    }
+}
+
+/// A Givens rotation, `[[c, s], [-s, c]]` acting on a pair of coordinates
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Givens<T> {
+    c: T,
+    s: T,
+    r: T,
+}
+
+impl<T> Givens<T>
+where
+    T: Float,
+{
+    /// Returns the rotation taking `(a, b)` to `(r, 0)`, where `r = ±‖(a, b)‖`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matrixes::{Givens, Matrix};
+    ///
+    /// let mut a = Matrix::from([[3.0_f64, 1.0], [4.0, 2.0]]);
+    /// let rotation = Givens::new(a[(0, 0)], a[(1, 0)]);
+    /// rotation.apply_to_rows(&mut a, 0, 1).expect("rows are within bounds");
+    ///
+    /// assert_eq!(rotation.r(), 5.0);
+    /// assert!((a[(0, 0)] - 5.0).abs() < 1e-12 && a[(1, 0)].abs() < 1e-12);
+    /// ```
+    pub fn new(a: T, b: T) -> Self {
+        let r = a.hypot(b);
+
+        if r.is_zero() {
+            return Self {
+                c: T::one(),
+                s: T::zero(),
+                r,
+            };
+        }
+
+        Self {
+            c: a / r,
+            s: b / r,
+            r,
+        }
+    }
+
+    /// Returns the cosine of the rotation
+    pub const fn c(&self) -> T {
+        self.c
+    }
+
+    /// Returns the sine of the rotation
+    pub const fn s(&self) -> T {
+        self.s
+    }
+
+    /// Returns the first coordinate of the rotated vector
+    pub const fn r(&self) -> T {
+        self.r
+    }
+
+    /// Replaces rows `i` and `j` of `matrix` with `c·rᵢ + s·rⱼ` and `c·rⱼ - s·rᵢ`, that is `GA`
+    ///
+    /// A rotation needs two distinct rows, so `matrix` is left unchanged when `i` equals `j`.
+    ///
+    /// # Errors
+    /// - i must index within bounds
+    /// - j must index within bounds
+    pub fn apply_to_rows<const R: usize, const C: usize>(
+        &self,
+        matrix: &mut Matrix<T, R, C>,
+        i: usize,
+        j: usize,
+    ) -> Result<(), IndexError> {
+        for index in [i, j] {
+            if index >= R {
+                return Err(IndexError::Row(index));
+            }
+        }
+
+        if i == j {
+            return Ok(());
+        }
+
+        for col in 0..C {
+            let (elem_i, elem_j) = (matrix[(i, col)], matrix[(j, col)]);
+            matrix[(i, col)] = self.c * elem_i + self.s * elem_j;
+            matrix[(j, col)] = self.c * elem_j - self.s * elem_i;
+        }
+
+        Ok(())
+    }
+
+    /// Replaces columns `i` and `j` of `matrix` with `c·cᵢ + s·cⱼ` and `c·cⱼ - s·cᵢ`, that is
+    /// `AGᵀ`
+    ///
+    /// A rotation needs two distinct columns, so `matrix` is left unchanged when `i` equals `j`.
+    ///
+    /// # Errors
+    /// - i must index within bounds
+    /// - j must index within bounds
+    pub fn apply_to_cols<const R: usize, const C: usize>(
+        &self,
+        matrix: &mut Matrix<T, R, C>,
+        i: usize,
+        j: usize,
+    ) -> Result<(), IndexError> {
+        for index in [i, j] {
+            if index >= C {
+                return Err(IndexError::Column(index));
+            }
+        }
+
+        if i == j {
+            return Ok(());
+        }
+
+        for row in 0..R {
+            let (elem_i, elem_j) = (matrix[(row, i)], matrix[(row, j)]);
+            matrix[(row, i)] = self.c * elem_i + self.s * elem_j;
+            matrix[(row, j)] = self.c * elem_j - self.s * elem_i;
+        }
+
+        Ok(())
+    }
+}
+
 impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
     pub fn map<F, U>(self, mut f: F) -> Matrix<U, R, C>
     where
@@ -0,0 +1,152 @@
+mod common;
+
+use common::{assert_close, to_f64};
+use fixtures::*;
+use matrixes::{
+    errors::{CholeskyError, SolveError},
+    Matrix,
+};
+use rstest::*;
+
+mod fixtures {
+    use super::*;
+
+    #[fixture]
+    pub fn covariance() -> Matrix<f64, 3, 3> {
+        Matrix::from([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ])
+    }
+
+    #[fixture]
+    pub fn indefinite() -> Matrix<f64, 3, 3> {
+        Matrix::from([[2.0, -1.0, 3.0], [-1.0, -4.0, 0.5], [3.0, 0.5, 1.0]])
+    }
+
+    #[fixture]
+    pub fn semidefinite() -> Matrix<f64, 3, 3> {
+        Matrix::from([[1.0, 0.0, 1.0], [0.0, 0.0, 0.0], [1.0, 0.0, 2.0]])
+    }
+}
+
+#[rstest]
+fn cholesky(covariance: Matrix<f64, 3, 3>) {
+    let l = *covariance
+        .cholesky()
+        .expect("matrix is positive definite")
+        .l();
+
+    assert_close(
+        &l,
+        &Matrix::from([[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]),
+    );
+    assert_close(&(l * l.to_transpose()), &covariance);
+}
+
+#[rstest]
+fn cholesky_badly_scaled() {
+    let matrix = Matrix::from([[1e-10, 0.0], [0.0, 1e10]]);
+    let l = *matrix.cholesky().expect("matrix is positive definite").l();
+
+    assert_close(&(l * l.to_transpose()), &matrix);
+}
+
+#[rstest]
+fn cholesky_reads_lower_triangle(covariance: Matrix<f64, 3, 3>) {
+    let mut upper_garbage = covariance;
+    upper_garbage[(0, 2)] = 1e30;
+
+    assert_eq!(upper_garbage.cholesky(), covariance.cholesky());
+    assert!(Matrix::from([[4.0, 1e30], [2.0, 5.0]]).cholesky().is_ok());
+}
+
+#[rstest]
+#[case(indefinite(), 1)]
+#[case(semidefinite(), 1)]
+#[case(Matrix::from([[-1.0, 0.0], [0.0, 1.0]]), 0)]
+fn cholesky_not_positive_definite<const N: usize>(
+    #[case] matrix: Matrix<f64, N, N>,
+    #[case] pivot: usize,
+) {
+    assert_eq!(
+        matrix.cholesky(),
+        Err(CholeskyError::NotPositiveDefinite { pivot })
+    );
+}
+
+#[rstest]
+#[case(covariance())]
+#[case(indefinite())]
+#[case(semidefinite())]
+#[case(Matrix::from([[1.0, 1.0, 0.0], [1.0, 1.0, 1.0], [0.0, 1.0, 1.0]]))]
+#[case(Matrix::from([[0.0, 1.0], [1.0, 0.0]]))]
+#[case(Matrix::from([[1e-20, 1.0], [1.0, 1.0]]))]
+#[case(Matrix::from([[0.0, 1.0, 2.0, 3.0], [1.0, 0.0, 4.0, 5.0], [2.0, 4.0, 0.0, 6.0], [3.0, 5.0, 6.0, 0.0]]))]
+#[case(Matrix::<f64, 3, 3>::new())]
+fn ldlt<const N: usize>(#[case] matrix: Matrix<f64, N, N>) {
+    let ldlt = matrix.ldlt();
+    let (l, p) = (ldlt.l(), ldlt.p());
+
+    assert_close(
+        &(l * ldlt.d() * l.to_transpose()),
+        &(p * matrix * p.to_transpose()),
+    );
+    for row in 0..N {
+        assert!((l[(row, row)] - 1.0).abs() < f64::EPSILON);
+        for col in row + 1..N {
+            assert!(l[(row, col)].abs() < f64::EPSILON);
+        }
+    }
+}
+
+#[rstest]
+fn ldlt_reads_lower_triangle(indefinite: Matrix<f64, 3, 3>) {
+    let mut upper_garbage = indefinite;
+    upper_garbage[(0, 2)] = 1e30;
+
+    assert_eq!(upper_garbage.ldlt(), indefinite.ldlt());
+}
+
+#[rstest]
+fn solve(covariance: Matrix<f64, 3, 3>, indefinite: Matrix<f64, 3, 3>) {
+    let b = Matrix::from([[1.0, 2.0], [-3.0, 0.5], [4.0, 0.0]]);
+
+    assert_close(
+        &covariance
+            .cholesky()
+            .expect("matrix is positive definite")
+            .solve(&b),
+        &covariance.solve(&b).expect("matrix is not singular"),
+    );
+    assert_close(
+        &covariance.ldlt().solve(&b).expect("matrix is not singular"),
+        &covariance.solve(&b).expect("matrix is not singular"),
+    );
+    assert_close(
+        &indefinite.ldlt().solve(&b).expect("matrix is not singular"),
+        &indefinite.solve(&b).expect("matrix is not singular"),
+    );
+}
+
+#[rstest]
+#[case(Matrix::from([[0.0, 1.0], [1.0, 0.0]]))]
+#[case(Matrix::from([[1e-20, 1.0], [1.0, 1.0]]))]
+#[case(Matrix::from([[0.0, 1.0, 2.0], [1.0, 0.0, 4.0], [2.0, 4.0, 0.0]]))]
+fn ldlt_solve_pivoted<const N: usize>(#[case] matrix: Matrix<f64, N, N>) {
+    let b = Matrix::<f64, N, 2>::from_fn(|row, col| to_f64(row + 2 * col) - 1.5);
+
+    assert_close(
+        &matrix.ldlt().solve(&b).expect("matrix is not singular"),
+        &matrix.solve(&b).expect("matrix is not singular"),
+    );
+}
+
+#[rstest]
+fn ldlt_solve_singular(semidefinite: Matrix<f64, 3, 3>) {
+    assert_eq!(
+        semidefinite.ldlt().solve(&Matrix::<f64, 3, 1>::new()),
+        Err(SolveError::Singular { pivot_col: 1 })
+    );
+}
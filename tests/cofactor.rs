@@ -0,0 +1,112 @@
+use matrixes::{errors::IndexError, Matrix};
+use rstest::*;
+
+#[fixture]
+fn three_by_three() -> Matrix<i64, 3, 3> {
+    Matrix::from([[2, -1, 3], [0, 4, 1], [5, 2, -2]])
+}
+
+#[fixture]
+fn four_by_four() -> Matrix<i64, 4, 4> {
+    Matrix::from([[1, 2, 0, -1], [3, 0, 2, 1], [0, -2, 1, 4], [2, 1, 3, 0]])
+}
+
+#[rstest]
+fn minors(three_by_three: Matrix<i64, 3, 3>) {
+    for i in 0..3 {
+        for j in 0..3 {
+            let rows: Vec<usize> = (0..3).filter(|&row| row != i).collect();
+            let cols: Vec<usize> = (0..3).filter(|&col| col != j).collect();
+            let expected = three_by_three[(rows[0], cols[0])] * three_by_three[(rows[1], cols[1])]
+                - three_by_three[(rows[0], cols[1])] * three_by_three[(rows[1], cols[0])];
+            let sign = if (i + j) % 2 == 0 { 1 } else { -1 };
+
+            assert_eq!(three_by_three.minor(i, j), Ok(expected));
+            assert_eq!(three_by_three.cofactor(i, j), Ok(sign * expected));
+            assert_eq!(three_by_three.cofactor_matrix()[(i, j)], sign * expected);
+        }
+    }
+}
+
+#[rstest]
+fn out_of_bounds(three_by_three: Matrix<i64, 3, 3>) {
+    assert_eq!(three_by_three.minor(3, 0), Err(IndexError::Row(3)));
+    assert_eq!(three_by_three.cofactor(0, 5), Err(IndexError::Column(5)));
+    assert_eq!(three_by_three.minor(3, 3), Err(IndexError::Both(3, 3)));
+}
+
+#[rstest]
+#[case(Matrix::from([[2, -1, 3, 0], [0, 4, 1, 0], [5, 2, -2, 0], [0, 0, 0, 1]]), -85)]
+#[case(four_by_four(), 32)]
+#[case(Matrix::from([[1, 2, 3, 4], [2, 4, 6, 8], [0, 1, 0, 1], [1, 1, 1, 1]]), 0)]
+fn adjugate(#[case] matrix: Matrix<i64, 4, 4>, #[case] det: i64) {
+    let adjugate = matrix.adjugate();
+    let scaled_identity = Matrix::from_fn(|row, col| if row == col { det } else { 0 });
+
+    assert_eq!(matrix * adjugate, scaled_identity);
+    assert_eq!(adjugate * matrix, scaled_identity);
+    assert_eq!(matrix.characteristic_polynomial()[0], det);
+}
+
+#[rstest]
+fn singular_adjugate() {
+    let matrix = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+
+    assert_eq!(
+        matrix.adjugate(),
+        Matrix::from([[-3, 6, -3], [6, -12, 6], [-3, 6, -3]])
+    );
+}
+
+#[rstest]
+fn characteristic_polynomial(four_by_four: Matrix<i64, 4, 4>) {
+    let polynomial = four_by_four.characteristic_polynomial();
+
+    assert_eq!(polynomial.len(), 5);
+    assert_eq!(polynomial[4], 1);
+    assert_eq!(polynomial[3], -four_by_four.trace());
+    assert_eq!(four_by_four.eval_polynomial(&polynomial), Matrix::new());
+}
+
+#[rstest]
+fn floating_point() {
+    let matrix = Matrix::from([[0.5_f64, 1.5, -2.0], [3.0, 0.25, 1.0], [-1.0, 2.0, 4.0]]);
+    let polynomial = matrix.characteristic_polynomial();
+
+    assert!((polynomial[0] + matrix.det()).abs() < 1e-12);
+    for (lhs, rhs) in matrix
+        .adjugate()
+        .iter()
+        .zip(&matrix.try_inverse().expect("matrix is not singular"))
+    {
+        assert!((lhs - rhs * matrix.det()).abs() < 1e-12);
+    }
+}
+
+#[rstest]
+fn small() {
+    assert_eq!(
+        Matrix::<i32, 0, 0>::new().characteristic_polynomial(),
+        vec![1]
+    );
+    assert_eq!(Matrix::from([[7]]).characteristic_polynomial(), vec![-7, 1]);
+    assert_eq!(Matrix::from([[7]]).adjugate(), Matrix::from([[1]]));
+}
+
+#[rstest]
+fn large_minors() {
+    let matrix = Matrix::from([[1_i64 << 40, 0], [0, 1 << 40]]);
+
+    assert_eq!(matrix.minor(0, 0), Ok(1 << 40));
+    assert_eq!(matrix.cofactor(1, 0), Ok(0));
+    assert_eq!(matrix.adjugate(), matrix);
+}
+
+#[rstest]
+fn minors_need_pivoting() {
+    let matrix = Matrix::from([[5, 0, 1, 2], [7, 0, 3, 1], [1, 2, 0, 4], [3, 1, 1, 0]]);
+
+    // Without row 0 and column 0 the submatrix starts with a zero pivot
+    assert_eq!(matrix.minor(0, 0), Ok(14));
+    assert_eq!(matrix.minor(3, 3), Ok(-16));
+}
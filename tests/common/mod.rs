@@ -0,0 +1,30 @@
+//! Helpers shared by the integration tests, each test crate uses only some of them
+#![allow(dead_code)]
+
+use matrixes::Matrix;
+
+/// Asserts that every element of `lhs` is within `1e-12` of the matching element of `rhs`
+pub fn assert_close<const R: usize, const C: usize>(
+    lhs: &Matrix<f64, R, C>,
+    rhs: &Matrix<f64, R, C>,
+) {
+    assert_close_within(lhs, rhs, 1e-12);
+}
+
+/// Asserts that every element of `lhs` is within `tol` of the matching element of `rhs`
+pub fn assert_close_within<const R: usize, const C: usize>(
+    lhs: &Matrix<f64, R, C>,
+    rhs: &Matrix<f64, R, C>,
+    tol: f64,
+) {
+    for (lhs, rhs) in lhs.iter().zip(rhs) {
+        assert!(
+            (lhs - rhs).abs() < tol,
+            "{lhs} is not within {tol} of {rhs}"
+        );
+    }
+}
+
+pub fn to_f64(n: usize) -> f64 {
+    f64::from(u32::try_from(n).expect(""))
+}
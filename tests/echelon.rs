@@ -0,0 +1,125 @@
+use matrixes::Matrix;
+use num_traits::Zero;
+use rstest::*;
+
+#[fixture]
+fn rank_two() -> Matrix<f64, 3, 4> {
+    Matrix::from([
+        [1.0, 2.0, 1.0, 4.0],
+        [2.0, 4.0, 0.0, 6.0],
+        [3.0, 6.0, 1.0, 10.0],
+    ])
+}
+
+#[fixture]
+fn inconsistent() -> Matrix<f64, 3, 3> {
+    Matrix::from([[1.0, 1.0, 1.0], [2.0, 2.0, 3.0], [0.0, 1.0, 1.0]])
+}
+
+#[fixture]
+fn no_rows() -> Matrix<f64, 0, 3> {
+    Matrix::new()
+}
+
+#[rstest]
+#[case(rank_two(), vec![0, 2])]
+#[case(inconsistent(), vec![0, 1, 2])]
+#[case(Matrix::<f64, 3, 3>::new(), vec![])]
+#[case(Matrix::from([[1e-10, 0.0], [0.0, 1e10]]), vec![0, 1])]
+#[case(no_rows(), vec![])]
+fn pivots<const R: usize, const C: usize>(
+    #[case] matrix: Matrix<f64, R, C>,
+    #[case] expected: Vec<usize>,
+) {
+    assert_eq!(matrix.row_echelon().pivots(), expected);
+    assert_eq!(matrix.rref().pivots(), expected);
+}
+
+#[rstest]
+fn row_echelon_is_upper_triangular(rank_two: Matrix<f64, 3, 4>) {
+    let echelon = rank_two.row_echelon();
+
+    for (row, &col) in echelon.pivots().iter().enumerate() {
+        for below in row + 1..3 {
+            assert!(echelon.matrix()[(below, col)].is_zero());
+        }
+    }
+    assert!(echelon
+        .matrix()
+        .get_row(2)
+        .expect("")
+        .iter()
+        .all(|elem| elem.is_zero()));
+}
+
+#[rstest]
+fn rref(rank_two: Matrix<f64, 3, 4>) {
+    let expected = Matrix::from([
+        [1.0, 2.0, 0.0, 3.0],
+        [0.0, 0.0, 1.0, 1.0],
+        [0.0, 0.0, 0.0, 0.0],
+    ]);
+    let rref = rank_two.rref();
+
+    for (actual, expected) in rref.matrix().iter().zip(&expected) {
+        assert!((actual - expected).abs() < 1e-12);
+    }
+    assert_eq!(rref.rank(), 2);
+    assert_eq!(rref.nullity(), 2);
+}
+
+#[rstest]
+#[case(rank_two(), true)]
+#[case(inconsistent(), false)]
+#[case(Matrix::<f64, 2, 3>::new(), true)]
+fn is_consistent<const R: usize, const C: usize>(
+    #[case] augmented: Matrix<f64, R, C>,
+    #[case] expected: bool,
+) {
+    assert_eq!(augmented.rref().is_consistent(), expected);
+}
+
+mod spaces {
+    use super::*;
+
+    #[rstest]
+    #[case(rank_two(), 2)]
+    #[case(inconsistent(), 3)]
+    #[case(Matrix::<f64, 2, 5>::new(), 0)]
+    #[case(no_rows(), 0)]
+    fn rank<const R: usize, const C: usize>(
+        #[case] matrix: Matrix<f64, R, C>,
+        #[case] expected: usize,
+    ) {
+        assert_eq!(matrix.rank(), expected);
+        assert_eq!(matrix.column_space().len(), expected);
+        assert_eq!(matrix.row_space().len(), expected);
+        assert_eq!(matrix.null_space().len(), C - expected);
+    }
+
+    #[rstest]
+    #[case(rank_two())]
+    #[case(inconsistent())]
+    #[case(Matrix::<f64, 2, 5>::new())]
+    #[case(no_rows())]
+    fn null_space_is_annihilated<const R: usize, const C: usize>(
+        #[case] matrix: Matrix<f64, R, C>,
+    ) {
+        for vector in matrix.null_space() {
+            for elem in &(matrix * vector) {
+                assert!(elem.abs() < 1e-12);
+            }
+        }
+    }
+
+    #[rstest]
+    fn column_space(rank_two: Matrix<f64, 3, 4>) {
+        assert_eq!(
+            rank_two.column_space(),
+            vec![
+                Matrix::from([[1.0], [2.0], [3.0]]),
+                Matrix::from([[1.0], [0.0], [1.0]])
+            ]
+        );
+    }
+}
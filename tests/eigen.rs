@@ -0,0 +1,178 @@
+mod common;
+
+use common::{assert_close, to_f64};
+use matrixes::Matrix;
+use num_complex::Complex;
+use rstest::*;
+use std::f64::consts::PI;
+
+#[fixture]
+fn hilbert() -> Matrix<f64, 5, 5> {
+    Matrix::from_fn(|row, col| 1.0 / to_f64(row + col + 1))
+}
+
+#[fixture]
+fn path_laplacian() -> Matrix<f64, 6, 6> {
+    Matrix::from_fn(|row, col| match row.abs_diff(col) {
+        0 if row == 0 || row == 5 => 1.0,
+        0 => 2.0,
+        1 => -1.0,
+        _ => 0.0,
+    })
+}
+
+mod symmetric {
+    use super::*;
+
+    #[rstest]
+    #[case(path_laplacian())]
+    #[case(Matrix::from([[4.0, 1.0, 2.0], [1.0, 3.0, 0.5], [2.0, 0.5, 1.0]]))]
+    #[case(Matrix::from([[0.0, 1.0], [1.0, 0.0]]))]
+    #[case(Matrix::<f64, 4, 4>::identity())]
+    #[case(Matrix::<f64, 3, 3>::new())]
+    #[case(Matrix::<f64, 0, 0>::new())]
+    #[case(hilbert())]
+    fn symmetric_eigen<const N: usize>(#[case] matrix: Matrix<f64, N, N>) {
+        let eigen = matrix.symmetric_eigen().expect("jacobi converges");
+        let v = eigen.eigenvectors();
+        let lambda = Matrix::from_fn(|row, col| {
+            if row == col {
+                eigen.eigenvalues()[row]
+            } else {
+                0.0
+            }
+        });
+
+        assert_close(&(matrix * v), &(v * lambda));
+        assert_close(&(v.to_transpose() * v), &Matrix::identity());
+        assert!(eigen
+            .eigenvalues()
+            .windows(2)
+            .all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[rstest]
+    #[case(1e200)]
+    #[case(1e-200)]
+    fn extreme_scales(#[case] scale: f64) {
+        let matrix = Matrix::from([[scale, scale], [scale, scale]]);
+        let eigen = matrix.symmetric_eigen().expect("jacobi converges");
+
+        assert!((eigen.eigenvalues()[0] / scale).abs() < 1e-12);
+        assert!((eigen.eigenvalues()[1] / scale - 2.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn path_graph_spectrum(path_laplacian: Matrix<f64, 6, 6>) {
+        let eigen = path_laplacian.symmetric_eigen().expect("jacobi converges");
+
+        for (k, value) in eigen.eigenvalues().iter().enumerate() {
+            let expected = 2.0 * (1.0 - (PI * to_f64(k) / 6.0).cos());
+            assert!((value - expected).abs() < 1e-12);
+        }
+    }
+}
+
+mod general {
+    use super::*;
+
+    #[fixture]
+    fn companion() -> Matrix<f64, 4, 4> {
+        // x⁴ - 2x³ + 6x² - 2x + 5 = (x² + 1)(x² - 2x + 5)
+        Matrix::from([
+            [2.0, -6.0, 2.0, -5.0],
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+        ])
+    }
+
+    fn sorted<const N: usize>(mut values: [Complex<f64>; N]) -> [Complex<f64>; N] {
+        values.sort_by(|lhs, rhs| {
+            (lhs.re, lhs.im)
+                .partial_cmp(&(rhs.re, rhs.im))
+                .expect("eigenvalues are not NaN")
+        });
+        values
+    }
+
+    #[rstest]
+    #[case(companion())]
+    #[case(Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]]))]
+    #[case(Matrix::from([[0.0, -1.0], [1.0, 0.0]]))]
+    #[case(Matrix::from([[2.0, 1.0], [0.0, 2.0]]))]
+    #[case(Matrix::from([[1.0, 2.0, 0.0], [0.0, 3.0, 4.0], [0.0, 0.0, 5.0]]))]
+    #[case(Matrix::<f64, 5, 5>::from_fn(|row, col| to_f64((row * 7 + col * 3) % 5) - 2.0))]
+    #[case(Matrix::<f64, 5, 5>::identity())]
+    #[case(Matrix::<f64, 3, 3>::new())]
+    #[case(Matrix::<f64, 1, 1>::from([[-4.0]]))]
+    #[case(Matrix::<f64, 0, 0>::new())]
+    #[case(hilbert())]
+    fn eigen<const N: usize>(#[case] matrix: Matrix<f64, N, N>) {
+        let eigen = matrix.eigen().expect("QR iteration converges");
+
+        for (col, &lambda) in eigen.eigenvalues().iter().enumerate() {
+            let mut norm = 0.0_f64;
+
+            for row in 0..N {
+                let av = (0..N).fold(Complex::new(0.0, 0.0), |acc, k| {
+                    acc + eigen.eigenvectors()[(k, col)] * matrix[(row, k)]
+                });
+
+                assert!((av - lambda * eigen.eigenvectors()[(row, col)]).norm() < 1e-10);
+                norm = norm.hypot(eigen.eigenvectors()[(row, col)].norm());
+            }
+
+            assert!((norm - 1.0).abs() < 1e-12);
+        }
+
+        assert_eq!(
+            eigen.eigenvalues(),
+            &matrix.eigenvalues().expect("QR iteration converges")
+        );
+    }
+
+    #[rstest]
+    fn complex_pairs(companion: Matrix<f64, 4, 4>) {
+        let values = companion.eigenvalues().expect("QR iteration converges");
+        let expected = [
+            Complex::new(0.0, -1.0),
+            Complex::new(0.0, 1.0),
+            Complex::new(1.0, -2.0),
+            Complex::new(1.0, 2.0),
+        ];
+
+        for (value, expected) in sorted(values).iter().zip(expected) {
+            assert!((value - expected).norm() < 1e-10);
+        }
+
+        for pair in values.chunks(2) {
+            assert!(pair[0].im >= 0.0);
+            assert!((pair[0] - pair[1].conj()).norm() < 1e-12);
+        }
+    }
+
+    #[rstest]
+    fn trace_and_determinant() {
+        let matrix = Matrix::<f64, 6, 6>::from_fn(|row, col| to_f64((row * 5 + col * 2) % 7) - 3.0);
+        let values: [Complex<f64>; 6] = matrix.eigenvalues().expect("QR iteration converges");
+
+        let sum = values.iter().sum::<Complex<f64>>();
+        let product = values.iter().product::<Complex<f64>>();
+
+        assert!((sum - Complex::new(matrix.trace(), 0.0)).norm() < 1e-10);
+        assert!((product - Complex::new(matrix.det(), 0.0)).norm() < 1e-8);
+    }
+
+    #[rstest]
+    fn stability() {
+        // A damped oscillator, x'' + 0.5x' + 4x = 0
+        let system = Matrix::from([[0.0, 1.0], [-4.0, -0.5]]);
+
+        assert!(system
+            .eigenvalues()
+            .expect("QR iteration converges")
+            .iter()
+            .all(|value| value.re < 0.0));
+    }
+}
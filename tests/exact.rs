@@ -0,0 +1,122 @@
+use matrixes::{errors::OverflowError, Matrix};
+use rstest::*;
+
+fn binomial(n: i64, k: i64) -> i64 {
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+#[fixture]
+fn pascal() -> Matrix<i64, 10, 10> {
+    Matrix::from_fn(|row, col| {
+        let (row, col) = (i64::try_from(row).expect(""), i64::try_from(col).expect(""));
+        binomial(row + col, row)
+    })
+}
+
+mod determinant {
+    use super::*;
+
+    #[rstest]
+    #[case(Matrix::from([[2, -1, 3], [0, 4, 1], [5, 2, -2]]), -85)]
+    #[case(Matrix::from([[0, 3, 1], [2, 1, 4], [5, 6, 0]]), 67)]
+    #[case(Matrix::from([[0, 0, 1], [0, 1, 0], [1, 0, 0]]), -1)]
+    #[case(Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]), 0)]
+    #[case(Matrix::from([[0, 0, 0], [1, 2, 3], [4, 5, 6]]), 0)]
+    fn small(#[case] matrix: Matrix<i64, 3, 3>, #[case] expected: i64) {
+        assert_eq!(matrix.determinant_exact(), Ok(expected));
+        assert_eq!(matrix.characteristic_polynomial()[0], -expected);
+    }
+
+    #[rstest]
+    fn pascal_matrix(pascal: Matrix<i64, 10, 10>) {
+        assert_eq!(pascal.determinant_exact(), Ok(1));
+    }
+
+    #[rstest]
+    fn beyond_float_precision() {
+        let big = 1_000_000_000_000_000_i128;
+        let matrix = Matrix::from([[big + 1, big], [big, big - 1]]);
+
+        assert_eq!(matrix.determinant_exact(), Ok(-1));
+    }
+
+    #[rstest]
+    fn overflow() {
+        assert_eq!(
+            Matrix::from([[100_i8, 0], [0, 100]]).determinant_exact(),
+            Err(OverflowError)
+        );
+        assert_eq!(
+            Matrix::from([[0_u32, 1], [1, 0]]).determinant_exact(),
+            Err(OverflowError)
+        );
+        assert_eq!(
+            Matrix::from([[i64::MIN, 0], [0, 1]]).determinant_exact(),
+            Ok(i64::MIN)
+        );
+    }
+
+    #[rstest]
+    fn unsigned_and_empty() {
+        assert_eq!(
+            Matrix::from([[3_u8, 1], [2, 4]]).determinant_exact(),
+            Ok(10)
+        );
+        assert_eq!(Matrix::<u8, 0, 0>::new().determinant_exact(), Ok(1));
+    }
+}
+
+mod echelon {
+    use super::*;
+
+    #[rstest]
+    #[case(Matrix::from([[1, 2, 1, 4], [2, 4, 0, 6], [3, 6, 1, 10]]), vec![0, 2])]
+    #[case(Matrix::from([[0, 0, 2, 1], [0, 3, 1, 0], [0, 6, 4, 1]]), vec![1, 2])]
+    #[case(Matrix::from([[2, 0, 0, 1], [0, 3, 0, 1], [0, 0, 5, 1]]), vec![0, 1, 2])]
+    #[case(Matrix::new(), vec![])]
+    fn pivots(#[case] matrix: Matrix<i64, 3, 4>, #[case] expected: Vec<usize>) {
+        let echelon = matrix.echelon_exact().expect("no overflow");
+        let float = matrix
+            .map(|elem| f64::from(i32::try_from(elem).expect("")))
+            .row_echelon();
+
+        assert_eq!(echelon.pivots(), expected);
+        assert_eq!(echelon.pivots(), float.pivots());
+
+        for (row, &pivot) in echelon.pivots().iter().enumerate() {
+            assert_ne!(echelon.matrix()[(row, pivot)], 0);
+
+            for below in row + 1..3 {
+                for col in 0..=pivot {
+                    assert_eq!(echelon.matrix()[(below, col)], 0);
+                }
+            }
+        }
+
+        for row in echelon.rank()..3 {
+            assert!(echelon
+                .matrix()
+                .get_row(row)
+                .expect("")
+                .iter()
+                .all(|elem| **elem == 0));
+        }
+    }
+
+    #[rstest]
+    fn inconsistent_system() {
+        let augmented = Matrix::from([[1_i32, 1, 2], [2, 2, 5]]);
+        let echelon = augmented.echelon_exact().expect("no overflow");
+
+        assert!(!echelon.is_consistent());
+        assert_eq!(echelon.matrix(), &Matrix::from([[1, 1, 2], [0, 0, 1]]));
+    }
+
+    #[rstest]
+    fn overflow() {
+        assert_eq!(
+            Matrix::from([[100_i8, 100, 0], [100, 0, 1]]).echelon_exact(),
+            Err(OverflowError)
+        );
+    }
+}
@@ -0,0 +1,153 @@
+mod common;
+
+use common::{assert_close, assert_close_within};
+use matrixes::{errors::MatrixFunctionError, Matrix};
+use rstest::*;
+use std::f64::consts::{E, PI};
+
+#[fixture]
+fn nonsymmetric() -> Matrix<f64, 3, 3> {
+    Matrix::from([[4.0, 1.0, -1.0], [0.5, 3.0, 2.0], [-1.0, 0.0, 5.0]])
+}
+
+mod expm {
+    use super::*;
+
+    #[rstest]
+    fn diagonal() {
+        let matrix = Matrix::from([[1.0, 0.0, 0.0], [0.0, -3.0, 0.0], [0.0, 0.0, 12.0]]);
+        let expected = Matrix::from([
+            [E, 0.0, 0.0],
+            [0.0, (-3.0_f64).exp(), 0.0],
+            [0.0, 0.0, 12.0_f64.exp()],
+        ]);
+
+        assert_close(&matrix.expm(), &expected);
+    }
+
+    #[rstest]
+    fn nilpotent() {
+        let matrix = Matrix::from([[0.0, 1.0, 0.0], [0.0, 0.0, 2.0], [0.0, 0.0, 0.0]]);
+
+        assert_close(
+            &matrix.expm(),
+            &Matrix::from([[1.0, 1.0, 1.0], [0.0, 1.0, 2.0], [0.0, 0.0, 1.0]]),
+        );
+    }
+
+    #[rstest]
+    #[case(1e-3)]
+    #[case(0.1)]
+    #[case(1.0)]
+    #[case(2.0)]
+    #[case(20.0)]
+    fn rotation(#[case] theta: f64) {
+        let expected = Matrix::from([[theta.cos(), -theta.sin()], [theta.sin(), theta.cos()]]);
+
+        assert_close(
+            &Matrix::from([[0.0, -theta], [theta, 0.0]]).expm(),
+            &expected,
+        );
+    }
+
+    #[rstest]
+    #[case(1e-3)]
+    #[case(0.2)]
+    #[case(1.0)]
+    fn inverse(nonsymmetric: Matrix<f64, 3, 3>, #[case] factor: f64) {
+        let mut matrix = nonsymmetric;
+        matrix.scale(factor);
+        let mut negated = matrix;
+        negated.scale(-1.0);
+
+        assert_close(&(matrix.expm() * negated.expm()), &Matrix::identity());
+    }
+
+    #[rstest]
+    fn zero_and_empty() {
+        assert_eq!(Matrix::<f64, 3, 3>::new().expm(), Matrix::identity());
+        assert_eq!(Matrix::<f64, 0, 0>::new().expm(), Matrix::new());
+    }
+}
+
+mod sqrtm {
+    use super::*;
+
+    #[rstest]
+    #[case(nonsymmetric())]
+    #[case(Matrix::from([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]))]
+    #[case(Matrix::from([[1.0, -1.0, 0.0], [1.0, 1.0, 0.0], [0.0, 0.0, 1e-4]]))]
+    #[case(Matrix::from([[1.0, 1e3, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]))]
+    #[case(Matrix::identity())]
+    fn squares_back(#[case] matrix: Matrix<f64, 3, 3>) {
+        let root = matrix.sqrtm().expect("matrix has a principal square root");
+
+        assert_close(&(root * root), &matrix);
+        assert!(root
+            .eigenvalues()
+            .expect("QR iteration converges")
+            .iter()
+            .all(|value| value.re > 0.0));
+    }
+
+    #[rstest]
+    #[case(Matrix::from([[-1.0, 0.0], [0.0, 4.0]]))]
+    #[case(Matrix::from([[0.0, 1.0], [0.0, 0.0]]))]
+    #[case(Matrix::from([[1.0, 2.0], [2.0, 1.0]]))]
+    fn negative_real_eigenvalue(#[case] matrix: Matrix<f64, 2, 2>) {
+        assert_eq!(
+            matrix.sqrtm(),
+            Err(MatrixFunctionError::NegativeRealEigenvalue)
+        );
+    }
+}
+
+mod logm {
+    use super::*;
+
+    #[rstest]
+    #[case(nonsymmetric())]
+    #[case(Matrix::from([[1e-3, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1e3]]))]
+    #[case(Matrix::from([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]))]
+    #[case(Matrix::from([[1.0, 5.0, 0.0], [0.0, 1.0, 5.0], [0.0, 0.0, 1.0]]))]
+    fn exponentiates_back(#[case] matrix: Matrix<f64, 3, 3>) {
+        let log = matrix.logm().expect("matrix has a principal logarithm");
+
+        // Relative to the largest element, as the diagonal case spans six orders of magnitude
+        assert_close_within(&log.expm(), &matrix, 1e-12 * matrix.norm_max().max(1.0));
+    }
+
+    #[rstest]
+    fn inverts_expm() {
+        let generator = Matrix::from([
+            [0.0, -PI / 2.0, 0.1],
+            [PI / 2.0, 0.0, 0.0],
+            [0.0, 0.3, -1.0],
+        ]);
+
+        assert_close(
+            &generator
+                .expm()
+                .logm()
+                .expect("matrix has a principal logarithm"),
+            &generator,
+        );
+    }
+
+    #[rstest]
+    fn identity() {
+        let log = Matrix::<f64, 4, 4>::identity()
+            .logm()
+            .expect("identity has a principal logarithm");
+
+        assert!(log.iter().all(|elem| elem.abs() < f64::EPSILON));
+    }
+
+    #[rstest]
+    fn negative_real_eigenvalue() {
+        assert_eq!(
+            Matrix::from([[-2.0, 0.0], [1.0, 3.0]]).logm(),
+            Err(MatrixFunctionError::NegativeRealEigenvalue)
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use fixtures::*;
+use matrixes::{errors::SolveError, Matrix};
+use rstest::*;
+
+mod fixtures {
+    use super::*;
+
+    #[fixture]
+    pub fn needs_pivoting() -> Matrix<f64, 4, 4> {
+        Matrix::from([
+            [0.0, 2.0, 1.0, -1.0],
+            [1.0, 1.0, 0.0, 3.0],
+            [3.0, 0.0, 1.0, 2.0],
+            [-2.0, 4.0, 5.0, 0.0],
+        ])
+    }
+
+    #[fixture]
+    pub fn singular() -> Matrix<f64, 3, 3> {
+        Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [5.0, 7.0, 9.0]])
+    }
+}
+
+fn assert_solves<const N: usize, const K: usize>(
+    a: &Matrix<f64, N, N>,
+    x: &Matrix<f64, N, K>,
+    b: &Matrix<f64, N, K>,
+) {
+    for (actual, expected) in (a * x).iter().zip(b) {
+        assert!((actual - expected).abs() < 1e-12);
+    }
+}
+
+#[rstest]
+#[case(Matrix::from([[1.0], [2.0], [3.0], [4.0]]))]
+#[case(Matrix::from([[1.0, 0.0], [0.0, -1.0], [2.5, 7.0], [0.0, 0.0]]))]
+#[case(Matrix::<f64, 4, 4>::identity())]
+fn solve<const K: usize>(needs_pivoting: Matrix<f64, 4, 4>, #[case] b: Matrix<f64, 4, K>) {
+    let x = needs_pivoting.solve(&b).expect("matrix is not singular");
+
+    assert_solves(&needs_pivoting, &x, &b);
+}
+
+#[rstest]
+fn solve_singular(singular: Matrix<f64, 3, 3>) {
+    assert_eq!(
+        singular.solve(&Matrix::<f64, 3, 1>::new()),
+        Err(SolveError::Singular { pivot_col: 2 })
+    );
+}
+
+#[rstest]
+fn reused_factorisation(needs_pivoting: Matrix<f64, 4, 4>) {
+    let lu = needs_pivoting.lu();
+
+    for k in 0..4 {
+        let b = Matrix::<f64, 4, 1>::from_fn(|row, _| if row == k { 1.0 } else { -1.0 });
+        let x = lu.solve(&b).expect("matrix is not singular");
+
+        assert_solves(&needs_pivoting, &x, &b);
+    }
+}
+
+#[rstest]
+#[case(vec![1.0, 2.0, 3.0], Err(SolveError::Dimensions { expected: 4, found: 3 }))]
+#[case(vec![1.0, 2.0, 3.0, 4.0], Ok(()))]
+#[case(vec![], Err(SolveError::Dimensions { expected: 4, found: 0 }))]
+fn solve_in_place(
+    needs_pivoting: Matrix<f64, 4, 4>,
+    #[case] mut b: Vec<f64>,
+    #[case] expected: Result<(), SolveError>,
+) {
+    let original = b.clone();
+
+    assert_eq!(needs_pivoting.lu().solve_in_place(&mut b), expected);
+
+    if expected.is_ok() {
+        let x = Matrix::<f64, 4, 1>::from_fn(|row, _| b[row]);
+        let b = Matrix::from_fn(|row, _| original[row]);
+        assert_solves(&needs_pivoting, &x, &b);
+    }
+}
+
+#[rstest]
+fn solve_in_place_singular(singular: Matrix<f64, 3, 3>) {
+    assert_eq!(
+        singular.lu().solve_in_place(&mut [1.0, 1.0, 1.0]),
+        Err(SolveError::Singular { pivot_col: 2 })
+    );
+}
+
+mod factors {
+    use super::*;
+    use matrixes::errors::InverseError;
+    use num_traits::{One, Zero};
+
+    #[rstest]
+    #[case(needs_pivoting())]
+    #[case(Matrix::from([[1e-20, 1.0], [1.0, 1.0]]))]
+    #[case(Matrix::<f64, 5, 5>::identity())]
+    fn reconstruct<const N: usize>(#[case] matrix: Matrix<f64, N, N>) {
+        let lu = matrix.lu();
+
+        for (actual, expected) in (lu.l() * lu.u()).iter().zip(&(lu.p() * matrix)) {
+            assert!((actual - expected).abs() < 1e-12);
+        }
+        for row in 0..N {
+            assert!(lu.l()[(row, row)].is_one());
+            for col in row + 1..N {
+                assert!(lu.l()[(row, col)].is_zero());
+                assert!(lu.u()[(col, row)].is_zero());
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(needs_pivoting(), -79.0)]
+    #[case(singular(), 0.0)]
+    #[case(Matrix::from([[0.0, 1.0], [1.0, 0.0]]), -1.0)]
+    #[case(Matrix::from([[1e-10, 0.0], [0.0, 1e10]]), 1.0)]
+    fn determinant<const N: usize>(#[case] matrix: Matrix<f64, N, N>, #[case] expected: f64) {
+        assert!((matrix.lu().determinant() - expected).abs() < 1e-12);
+        assert!((matrix.lu().determinant() - matrix.det()).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn inverse(needs_pivoting: Matrix<f64, 4, 4>) {
+        let inverse = needs_pivoting
+            .lu()
+            .inverse()
+            .expect("matrix is not singular");
+
+        for (actual, expected) in inverse.iter().zip(
+            &needs_pivoting
+                .try_inverse()
+                .expect("matrix is not singular"),
+        ) {
+            assert!((actual - expected).abs() < 1e-12);
+        }
+    }
+
+    #[rstest]
+    fn inverse_singular(singular: Matrix<f64, 3, 3>) {
+        assert_eq!(
+            singular.lu().inverse(),
+            Err(InverseError::Singular { pivot_col: 2 })
+        );
+    }
+}
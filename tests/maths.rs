@@ -0,0 +1,304 @@
+use fixtures::*;
+use matrixes::Matrix;
+use rstest::*;
+
+mod fixtures {
+    use super::*;
+
+    #[fixture]
+    pub fn small_identity() -> Matrix<f64, 3, 3> {
+        Matrix::identity()
+    }
+
+    #[fixture]
+    pub fn needs_pivoting() -> Matrix<f64, 3, 3> {
+        Matrix::from([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 1.0]])
+    }
+
+    #[fixture]
+    pub fn singular() -> Matrix<f64, 3, 3> {
+        Matrix::from([[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]])
+    }
+
+    #[fixture]
+    pub fn empty() -> Matrix<f64, 0, 0> {
+        Matrix::new()
+    }
+}
+
+#[rstest]
+#[case(small_identity(), 1.0)]
+#[case(needs_pivoting(), -5.0)]
+#[case(Matrix::from([[4.0, 3.0], [6.0, 3.0]]), -6.0)]
+#[case(Matrix::from([[-7.5]]), -7.5)]
+fn det<const N: usize>(#[case] matrix: Matrix<f64, N, N>, #[case] expected: f64) {
+    assert!((matrix.det() - expected).abs() < 1e-12);
+}
+
+#[rstest]
+#[case(Matrix::from([[1e-10, 0.0], [0.0, 1e10]]), 1.0)]
+#[case(Matrix::from([[1e-10, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1e10]]), 1.0)]
+fn det_badly_scaled<const N: usize>(#[case] matrix: Matrix<f64, N, N>, #[case] expected: f64) {
+    assert!((matrix.det() - expected).abs() < 1e-12);
+}
+
+#[rstest]
+#[case(singular(), 0.0)]
+#[case(empty(), 1.0)]
+#[case(Matrix::from([[1.0, 2.0], [2.0, 4.0]]), 0.0)]
+fn det_exact<const N: usize>(#[case] matrix: Matrix<f64, N, N>, #[case] expected: f64) {
+    assert_eq!(matrix.det().to_bits(), expected.to_bits());
+}
+
+mod inverse {
+    use super::*;
+    use matrixes::errors::InverseError;
+
+    #[rstest]
+    #[case(small_identity())]
+    #[case(needs_pivoting())]
+    #[case(Matrix::from([[2.0, -1.0, 0.0, 0.0], [-1.0, 2.0, -1.0, 0.0], [0.0, -1.0, 2.0, -1.0], [0.0, 0.0, -1.0, 2.0]]))]
+    #[case(empty())]
+    #[case(Matrix::from([[1e-10, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1e10]]))]
+    fn try_inverse<const N: usize>(#[case] matrix: Matrix<f64, N, N>) {
+        let inverse = matrix.try_inverse().expect("matrix is not singular");
+
+        for elem in &(matrix * inverse - Matrix::identity()) {
+            assert!(elem.abs() < 1e-12);
+        }
+    }
+
+    #[rstest]
+    #[case(singular(), 2)]
+    #[case(Matrix::from([[0.0, 0.0], [0.0, 1.0]]), 0)]
+    fn try_inverse_singular<const N: usize>(
+        #[case] matrix: Matrix<f64, N, N>,
+        #[case] pivot_col: usize,
+    ) {
+        assert_eq!(
+            matrix.try_inverse(),
+            Err(InverseError::Singular { pivot_col })
+        );
+    }
+
+    #[rstest]
+    #[case(needs_pivoting())]
+    #[case(Matrix::from([[0.0, 1.0], [1.0, 0.0]]))]
+    fn invert_in_place<const N: usize>(#[case] matrix: Matrix<f64, N, N>) {
+        let mut inverted = matrix;
+        inverted.invert_in_place().expect("matrix is not singular");
+
+        assert_eq!(Ok(inverted), matrix.try_inverse());
+    }
+}
+
+mod scalar {
+    use super::*;
+
+    #[rstest]
+    #[allow(clippy::op_ref)]
+    fn mul() {
+        let matrix = Matrix::from([[1, -2, 3], [4, 0, -6]]);
+        let expected = Matrix::from([[3, -6, 9], [12, 0, -18]]);
+
+        assert_eq!(matrix * 3, expected);
+        assert_eq!(&matrix * 3, expected);
+        assert_eq!(matrix * &3, expected);
+        assert_eq!(&matrix * &3, expected);
+        assert_eq!(3 * matrix, expected);
+        assert_eq!(3 * &matrix, expected);
+        assert_eq!(&3 * matrix, expected);
+        assert_eq!(&3 * &matrix, expected);
+
+        let mut scaled = matrix;
+        scaled *= 3;
+        assert_eq!(scaled, expected);
+
+        let mut scaled = matrix;
+        scaled *= &3;
+        assert_eq!(scaled, expected);
+    }
+
+    #[rstest]
+    #[allow(clippy::op_ref)]
+    fn div() {
+        let matrix = Matrix::from([[3_u8, 7], [9, 12]]);
+        let expected = Matrix::from([[1, 2], [3, 4]]);
+
+        assert_eq!(matrix / 3, expected);
+        assert_eq!(&matrix / 3, expected);
+        assert_eq!(matrix / &3, expected);
+        assert_eq!(&matrix / &3, expected);
+
+        let mut divided = matrix;
+        divided /= 3;
+        assert_eq!(divided, expected);
+
+        let mut divided = matrix;
+        divided /= &3;
+        assert_eq!(divided, expected);
+    }
+
+    #[rstest]
+    fn expression(needs_pivoting: Matrix<f64, 3, 3>, singular: Matrix<f64, 3, 3>) {
+        let result = 2.0 * &needs_pivoting - singular / 4.0;
+
+        for (row, col) in (0..3).flat_map(|row| (0..3).map(move |col| (row, col))) {
+            let expected = 2.0f64.mul_add(needs_pivoting[(row, col)], -singular[(row, col)] / 4.0);
+
+            assert!((result[(row, col)] - expected).abs() < 1e-12);
+        }
+    }
+
+    #[rstest]
+    fn mul_div_round_trip(needs_pivoting: Matrix<f64, 3, 3>) {
+        let mut matrix = needs_pivoting * 0.25;
+        matrix /= 0.25;
+
+        assert_eq!(matrix, needs_pivoting);
+        assert_eq!(2.0_f32 * Matrix::from([[0.5_f32]]), Matrix::from([[1.0]]));
+    }
+}
+
+mod product {
+    use super::*;
+
+    #[rstest]
+    fn mul_assign(needs_pivoting: Matrix<f64, 3, 3>, singular: Matrix<f64, 3, 3>) {
+        let mut product = needs_pivoting;
+        product *= singular;
+        assert_eq!(product, needs_pivoting * singular);
+
+        let mut product = needs_pivoting;
+        product *= &singular;
+        assert_eq!(product, needs_pivoting * singular);
+    }
+
+    #[rstest]
+    fn mul_into() {
+        let a = Matrix::from([[1, -2, 0, 3], [2, 1, 4, -1]]);
+        let b = Matrix::from([[1, 0, 2], [0, 3, -1], [2, 1, 1], [-1, 0, 5]]);
+        let mut out = Matrix::from([[7; 3]; 2]);
+        a.mul_into(&b, &mut out);
+
+        assert_eq!(out, Matrix::from([[-2, -6, 19], [11, 7, 2]]));
+        assert_eq!(out, a * b);
+    }
+
+    #[rstest]
+    fn spans_blocks() {
+        let value = |row: usize, col: usize| i64::try_from((row * 7 + col * 3) % 11).expect("") - 5;
+        let lhs = Matrix::<i64, 70, 130>::from_fn(value);
+        let rhs = Matrix::<i64, 130, 65>::from_fn(|row, col| value(col, row));
+
+        let expected = Matrix::<i64, 70, 65>::from_fn(|i, j| {
+            (0..130).map(|k| lhs[(i, k)] * rhs[(k, j)]).sum()
+        });
+
+        assert_eq!(lhs * rhs, expected);
+
+        let mut c = expected;
+        Matrix::gemm(2, &lhs, &rhs, -1, &mut c);
+        assert_eq!(c, expected);
+    }
+
+    #[rstest]
+    #[case(1, 0)]
+    #[case(2, 1)]
+    #[case(-1, 3)]
+    #[case(0, -2)]
+    fn gemm(#[case] alpha: i64, #[case] beta: i64) {
+        let a = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix::from([[0, 1], [-1, 2], [3, 0]]);
+        let c = Matrix::from([[5, -3], [2, 8]]);
+
+        let mut result = c;
+        Matrix::gemm(alpha, &a, &b, beta, &mut result);
+
+        assert_eq!(result, a * b * alpha + c * beta);
+    }
+
+    #[rstest]
+    fn gemm_ignores_c_when_beta_is_zero(needs_pivoting: Matrix<f64, 3, 3>) {
+        let mut c = Matrix::from([[f64::NAN; 3]; 3]);
+        Matrix::gemm(1.0, &needs_pivoting, &Matrix::identity(), 0.0, &mut c);
+
+        assert_eq!(c, needs_pivoting);
+    }
+}
+
+mod elementwise {
+    use super::*;
+    use matrixes::errors::TryFromSlicesError;
+
+    #[fixture]
+    fn lhs() -> Matrix<i32, 2, 3> {
+        Matrix::from([[1, -2, 3], [4, 0, -6]])
+    }
+
+    #[fixture]
+    fn rhs() -> Matrix<i32, 2, 3> {
+        Matrix::from([[5, 1, -1], [0, 2, 2]])
+    }
+
+    #[rstest]
+    #[allow(clippy::op_ref)]
+    fn add(lhs: Matrix<i32, 2, 3>, rhs: Matrix<i32, 2, 3>) {
+        let expected = Matrix::from([[6, -1, 2], [4, 2, -4]]);
+
+        assert_eq!(lhs + rhs, expected);
+        assert_eq!(lhs + &rhs, expected);
+        assert_eq!(&lhs + rhs, expected);
+        assert_eq!(&lhs + &rhs, expected);
+
+        let mut sum = lhs;
+        sum += rhs;
+        assert_eq!(sum, expected);
+
+        let mut sum = lhs;
+        sum += &rhs;
+        assert_eq!(sum, expected);
+    }
+
+    #[rstest]
+    #[allow(clippy::op_ref)]
+    fn sub(lhs: Matrix<i32, 2, 3>, rhs: Matrix<i32, 2, 3>) {
+        let expected = Matrix::from([[-4, -3, 4], [4, -2, -8]]);
+
+        assert_eq!(lhs - rhs, expected);
+        assert_eq!(lhs - &rhs, expected);
+        assert_eq!(&lhs - rhs, expected);
+        assert_eq!(&lhs - &rhs, expected);
+
+        let mut difference = lhs;
+        difference -= rhs;
+        assert_eq!(difference, expected);
+
+        let mut difference = lhs;
+        difference -= &rhs;
+        assert_eq!(difference, expected);
+    }
+
+    #[rstest]
+    fn into_iter(lhs: Matrix<i32, 2, 3>) {
+        assert_eq!(lhs.into_iter().collect::<Vec<_>>(), [1, -2, 3, 4, 0, -6]);
+        assert_eq!(lhs.into_iter().count(), 6);
+        assert_eq!(Matrix::<i32, 0, 3>::new().into_iter().next(), None);
+    }
+
+    #[rstest]
+    fn try_from_slices(lhs: Matrix<i32, 2, 3>) {
+        let rows = [vec![1, -2, 3], vec![4, 0, -6]];
+        assert_eq!(Matrix::try_from(&rows[..]), Ok(lhs));
+
+        let short = [vec![1, -2, 3], vec![4, 0]];
+        assert_eq!(
+            Matrix::<i32, 2, 3>::try_from(&short[..]),
+            Err(TryFromSlicesError::Columns(2, 1))
+        );
+        assert_eq!(
+            Matrix::<i32, 3, 3>::try_from(&rows[..]),
+            Err(TryFromSlicesError::Rows(2))
+        );
+    }
+}
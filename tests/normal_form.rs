@@ -0,0 +1,97 @@
+use matrixes::{errors::OverflowError, Matrix};
+use rstest::*;
+
+fn assert_unimodular<const N: usize>(matrix: &Matrix<i64, N, N>) {
+    let det = matrix.determinant_exact().expect("no overflow");
+
+    assert!(det == 1 || det == -1);
+}
+
+#[rstest]
+#[case(Matrix::from([[2, 4, 4, 1], [-6, 6, 12, 0], [10, -4, -16, 3]]))]
+#[case(Matrix::from([[0, 0, 6, 0], [0, 4, 0, 0], [0, 0, 0, 10]]))]
+#[case(Matrix::from([[3, 6, 9, 12], [2, 4, 6, 8], [1, 2, 3, 4]]))]
+#[case(Matrix::from([[-7, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]]))]
+#[case(Matrix::new())]
+fn smith_normal_form(#[case] matrix: Matrix<i64, 3, 4>) {
+    let (u, d, v) = matrix.smith_normal_form().expect("no overflow");
+
+    assert_eq!(u * matrix * v, d);
+    assert_unimodular(&u);
+    assert_unimodular(&v);
+
+    for row in 0..3 {
+        for col in 0..4 {
+            if row != col {
+                assert_eq!(d[(row, col)], 0);
+            }
+        }
+    }
+
+    for i in 0..2 {
+        assert!(d[(i, i)] >= 0);
+        assert!(d[(i, i)] == 0 && d[(i + 1, i + 1)] == 0 || d[(i + 1, i + 1)] % d[(i, i)] == 0);
+    }
+}
+
+#[rstest]
+fn invariant_factors() {
+    // Z⁴ / im(A) ≅ Z/2 ⊕ Z/6 ⊕ Z
+    let matrix = Matrix::from([[2, 4, 4], [-6, 6, 12], [10, -4, -16], [0, 0, 0]]);
+    let (_, d, _) = matrix.smith_normal_form().expect("no overflow");
+
+    assert_eq!(
+        d,
+        Matrix::from([[2, 0, 0], [0, 6, 0], [0, 0, 12], [0, 0, 0]])
+    );
+}
+
+#[rstest]
+#[case(Matrix::from([[2, 3, 6, 2], [4, 1, 2, 7], [6, 8, 10, -1]]))]
+#[case(Matrix::from([[0, 3, 1, 2], [0, 6, 2, 4], [0, 0, 5, -5]]))]
+#[case(Matrix::from([[-4, 2, 0, 1], [6, -3, 0, 2], [2, -1, 0, 3]]))]
+#[case(Matrix::new())]
+fn hermite_normal_form(#[case] matrix: Matrix<i64, 3, 4>) {
+    let (u, h) = matrix.hermite_normal_form().expect("no overflow");
+
+    assert_eq!(u * matrix, h);
+    assert_unimodular(&u);
+
+    let mut previous_pivot = None;
+    for row in 0..3 {
+        let Some(pivot) = (0..4).find(|&col| h[(row, col)] != 0) else {
+            assert!((row..3).all(|rest| (0..4).all(|col| h[(rest, col)] == 0)));
+            break;
+        };
+
+        assert!(previous_pivot.is_none_or(|previous| pivot > previous));
+        assert!(h[(row, pivot)] > 0);
+        for above in 0..row {
+            assert!((0..h[(row, pivot)]).contains(&h[(above, pivot)]));
+        }
+
+        previous_pivot = Some(pivot);
+    }
+
+    let shuffle = Matrix::from([[1, 2, 0], [0, 1, 0], [3, 7, 1]]);
+    let (_, shuffled) = (shuffle * matrix)
+        .hermite_normal_form()
+        .expect("no overflow");
+
+    assert_eq!(shuffled, h);
+}
+
+#[rstest]
+fn overflow() {
+    let matrix = Matrix::from([[i8::MAX, 1], [i8::MIN, 1]]);
+
+    assert_eq!(matrix.smith_normal_form(), Err(OverflowError));
+    assert_eq!(
+        Matrix::from([[i8::MAX - 1, 1], [i8::MAX, 2]]).hermite_normal_form(),
+        Err(OverflowError)
+    );
+    assert_eq!(
+        Matrix::from([[1_i8, i8::MAX], [0, 1]]).hermite_normal_form(),
+        Ok((Matrix::from([[1, -i8::MAX], [0, 1]]), Matrix::identity()))
+    );
+}
@@ -0,0 +1,108 @@
+mod common;
+
+use common::to_f64;
+use matrixes::{Matrix, Norm};
+use rstest::*;
+
+#[fixture]
+fn three_by_two() -> Matrix<f64, 3, 2> {
+    Matrix::from([[1.0, -7.0], [-2.0, -3.0], [4.0, 2.0]])
+}
+
+#[fixture]
+fn hilbert() -> Matrix<f64, 4, 4> {
+    Matrix::from_fn(|row, col| 1.0 / to_f64(row + col + 1))
+}
+
+#[rstest]
+fn norms(three_by_two: Matrix<f64, 3, 2>) {
+    assert!((three_by_two.norm_1() - 12.0).abs() < f64::EPSILON);
+    assert!((three_by_two.norm_inf() - 8.0).abs() < f64::EPSILON);
+    assert!((three_by_two.norm_max() - 7.0).abs() < f64::EPSILON);
+    assert!((three_by_two.norm_frobenius() - 83.0_f64.sqrt()).abs() < 1e-12);
+
+    let gram = three_by_two.to_transpose() * three_by_two;
+    let largest = gram
+        .symmetric_eigen()
+        .expect("jacobi converges")
+        .eigenvalues()[1];
+
+    assert!((three_by_two.norm_2() - largest.sqrt()).abs() < 1e-12);
+}
+
+#[rstest]
+#[case(Norm::One)]
+#[case(Norm::Two)]
+#[case(Norm::Infinity)]
+#[case(Norm::Frobenius)]
+fn norm_inequalities(three_by_two: Matrix<f64, 3, 2>, #[case] norm: Norm) {
+    let other = Matrix::from([[0.5, 2.0, -1.0], [3.0, 0.0, 1.0]]);
+
+    let bound = three_by_two.norm(norm) * other.norm(norm);
+
+    assert!((three_by_two * other).norm(norm) <= bound * (1.0 + 1e-12));
+    assert!(three_by_two.norm_2() <= three_by_two.norm_frobenius() + 1e-12);
+    assert!(Matrix::<f64, 0, 3>::new().norm(norm).abs() < f64::EPSILON);
+}
+
+#[rstest]
+#[case(Norm::One, 28_375.0)]
+#[case(Norm::Infinity, 28_375.0)]
+fn hilbert_condition_number(hilbert: Matrix<f64, 4, 4>, #[case] norm: Norm, #[case] expected: f64) {
+    assert!((hilbert.condition_number(norm) - expected).abs() < 1e-6);
+}
+
+#[rstest]
+fn condition_number_two(hilbert: Matrix<f64, 4, 4>) {
+    let values = hilbert.singular_values().expect("jacobi converges");
+
+    assert!((hilbert.condition_number(Norm::Two) - values[0] / values[3]).abs() < 1e-6);
+    assert!(hilbert.condition_number(Norm::Two) <= hilbert.condition_number(Norm::Frobenius));
+}
+
+#[rstest]
+#[case(Norm::One)]
+#[case(Norm::Two)]
+#[case(Norm::Infinity)]
+#[case(Norm::Frobenius)]
+fn condition_number_singular(#[case] norm: Norm) {
+    let singular = Matrix::from([[1.0_f64, 2.0], [2.0, 4.0]]);
+
+    assert!(singular.condition_number(norm).is_infinite());
+}
+
+mod estimate {
+    use super::*;
+
+    #[rstest]
+    #[case(hilbert())]
+    #[case(Matrix::from_fn(|row, col| to_f64((row * 5 + col * 2) % 7) - 3.0))]
+    #[case(Matrix::from_fn(|row, col| if row == col { 1.0 } else if col == row + 1 { -10.0 } else { 0.0 }))]
+    #[case(Matrix::identity())]
+    fn close_to_exact(#[case] matrix: Matrix<f64, 4, 4>) {
+        let estimate = matrix.lu().condition_number_estimate();
+        let exact = matrix.condition_number(Norm::One);
+
+        assert!(estimate <= exact * (1.0 + 1e-10));
+        assert!(estimate >= exact / 3.0);
+    }
+
+    #[rstest]
+    fn singular() {
+        let singular = Matrix::from([[1.0_f64, 2.0, 3.0], [4.0, 5.0, 6.0], [5.0, 7.0, 9.0]]);
+
+        assert!(singular.lu().condition_number_estimate().is_infinite());
+    }
+
+    #[rstest]
+    fn small() {
+        assert!(
+            (Matrix::<f64, 0, 0>::new().lu().condition_number_estimate() - 1.0).abs()
+                < f64::EPSILON
+        );
+        assert!(
+            (Matrix::from([[-4.0_f64]]).lu().condition_number_estimate() - 1.0).abs()
+                < f64::EPSILON
+        );
+    }
+}
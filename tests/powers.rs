@@ -0,0 +1,75 @@
+mod common;
+
+use common::assert_close;
+use matrixes::Matrix;
+use rstest::*;
+
+#[fixture]
+fn markov() -> Matrix<f64, 3, 3> {
+    Matrix::from([[0.9, 0.075, 0.025], [0.15, 0.8, 0.05], [0.25, 0.25, 0.5]])
+}
+
+#[rstest]
+#[case(0)]
+#[case(1)]
+#[case(2)]
+#[case(7)]
+#[case(16)]
+fn pow(#[case] exp: u32) {
+    let matrix = Matrix::from([[1, -1, 2], [0, 2, 1], [3, 0, -1]]);
+    let repeated = (0..exp).fold(Matrix::identity(), |acc, _| acc * matrix);
+
+    assert_eq!(matrix.pow(exp), repeated);
+    assert_eq!(matrix.checked_pow(exp), Some(repeated));
+}
+
+#[rstest]
+fn fibonacci() {
+    let fibonacci = Matrix::from([[1_u128, 1], [1, 0]]);
+
+    assert_eq!(fibonacci.pow(100)[(0, 1)], 354_224_848_179_261_915_075);
+}
+
+#[rstest]
+fn markov_steps(markov: Matrix<f64, 3, 3>) {
+    let steps = markov.pow(512);
+
+    assert_close(&(steps * markov), &steps);
+    for row in 0..3 {
+        assert!((steps.get_row(row).expect("").into_iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    }
+}
+
+#[rstest]
+fn checked_pow_overflow() {
+    let matrix = Matrix::from([[2_i32, 0], [0, -2]]);
+
+    assert_eq!(
+        matrix.checked_pow(30),
+        Some(Matrix::from([[1 << 30, 0], [0, 1 << 30]]))
+    );
+    assert_eq!(matrix.checked_pow(31), None);
+    assert_eq!(
+        Matrix::from([[0_u8, 200], [1, 0]]).checked_pow(2),
+        Some(Matrix::from([[200, 0], [0, 200]]))
+    );
+    assert_eq!(Matrix::from([[2_u8, 200], [1, 0]]).checked_pow(2), None);
+}
+
+#[rstest]
+#[case(&[])]
+#[case(&[3.0])]
+#[case(&[1.0, -2.0])]
+#[case(&[0.5, 0.0, 2.0, -1.0])]
+fn eval_polynomial(markov: Matrix<f64, 3, 3>, #[case] coefficients: &[f64]) {
+    let expected = coefficients
+        .iter()
+        .zip(0..)
+        .fold(Matrix::new(), |acc, (&coefficient, exp)| {
+            let mut term = markov.pow(exp);
+            term.scale(coefficient);
+            acc + term
+        });
+
+    assert_close(&markov.eval_polynomial(coefficients), &expected);
+}
@@ -0,0 +1,118 @@
+use matrixes::Matrix;
+use rstest::*;
+
+#[fixture]
+fn a() -> Matrix<i64, 2, 3> {
+    Matrix::from([[1, -2, 3], [0, 4, -1]])
+}
+
+#[fixture]
+fn b() -> Matrix<i64, 3, 2> {
+    Matrix::from([[2, 1], [-1, 0], [5, 3]])
+}
+
+mod kronecker {
+    use super::*;
+
+    #[rstest]
+    fn shape(a: Matrix<i64, 2, 3>, b: Matrix<i64, 3, 2>) {
+        let product: Matrix<i64, 6, 6> = a.kronecker(&b);
+
+        for row in 0..6 {
+            for col in 0..6 {
+                assert_eq!(
+                    product[(row, col)],
+                    a[(row / 3, col / 2)] * b[(row % 3, col % 2)]
+                );
+            }
+        }
+    }
+
+    #[rstest]
+    fn mixed_product(a: Matrix<i64, 2, 3>, b: Matrix<i64, 3, 2>) {
+        let c = Matrix::from([[1, 0], [2, -3], [-1, 1]]);
+        let d = Matrix::from([[4, -2, 1], [0, 1, 2]]);
+
+        let lhs: Matrix<i64, 6, 6> = a.kronecker(&b);
+        let rhs: Matrix<i64, 6, 6> = c.kronecker(&d);
+        let expected: Matrix<i64, 6, 6> = (a * c).kronecker(&(b * d));
+
+        assert_eq!(lhs * rhs, expected);
+    }
+
+    #[rstest]
+    fn trace() {
+        let a = Matrix::from([[1, 2], [3, 4]]);
+        let b = Matrix::from([[2, 0, 1], [1, -1, 3], [0, 5, 6]]);
+        let product: Matrix<i64, 6, 6> = a.kronecker(&b);
+
+        assert_eq!(product.trace(), a.trace() * b.trace());
+    }
+
+    #[rstest]
+    fn identity(b: Matrix<i64, 3, 2>) {
+        let product: Matrix<i64, 6, 4> = Matrix::<i64, 2, 2>::identity().kronecker(&b);
+
+        for row in 0..6 {
+            for col in 0..4 {
+                let expected = if row / 3 == col / 2 {
+                    b[(row % 3, col % 2)]
+                } else {
+                    0
+                };
+
+                assert_eq!(product[(row, col)], expected);
+            }
+        }
+    }
+}
+
+mod hadamard {
+    use super::*;
+
+    #[rstest]
+    fn commutative(a: Matrix<i64, 2, 3>) {
+        let other = Matrix::from([[3, 0, -2], [7, 1, 1]]);
+
+        assert_eq!(a.hadamard(&other), other.hadamard(&a));
+        assert_eq!(a.component_mul(&other), a.hadamard(&other));
+    }
+
+    #[rstest]
+    fn ones(a: Matrix<i64, 2, 3>) {
+        assert_eq!(a.hadamard(&Matrix::from([[1; 3]; 2])), a);
+        assert_eq!(
+            a.hadamard(&Matrix::from([[0; 3]; 2])),
+            Matrix::from([[0; 3]; 2])
+        );
+    }
+
+    #[rstest]
+    fn differs_from_product() {
+        let matrix = Matrix::from([[1, 2], [3, 4]]);
+
+        assert_eq!(matrix.hadamard(&matrix), Matrix::from([[1, 4], [9, 16]]));
+        assert_ne!(matrix.hadamard(&matrix), matrix * matrix);
+    }
+}
+
+mod outer {
+    use super::*;
+
+    #[rstest]
+    fn matches_product() {
+        let col = Matrix::from([[1], [-2], [3], [0]]);
+        let row = Matrix::from([[5, 6, -7]]);
+
+        assert_eq!(Matrix::outer(&col, &row), col * row);
+    }
+
+    #[rstest]
+    fn kronecker_of_vectors() {
+        let col = Matrix::from([[2], [3]]);
+        let row = Matrix::from([[1, 4, 5]]);
+        let product: Matrix<i64, 2, 3> = col.kronecker(&row);
+
+        assert_eq!(Matrix::outer(&col, &row), product);
+    }
+}
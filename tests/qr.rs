@@ -0,0 +1,66 @@
+mod common;
+
+use common::assert_close;
+use matrixes::Matrix;
+use rstest::*;
+
+#[rstest]
+#[case(Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0], [1.0, 0.0, 1.0]]))]
+#[case(Matrix::from([[2.0, -1.0, 0.0, 4.0, 1.0], [0.0, 3.0, 1.0, 1.0, 2.0], [5.0, 0.0, 0.0, 1.0, 1.0]]))]
+#[case(Matrix::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 0.0, 0.0]]))]
+#[case(Matrix::from([[0.0, 1.0], [0.0, 0.0]]))]
+#[case(Matrix::from([[1e-200, 2e-200], [3e-200, 4e-200]]))]
+#[case(Matrix::<f64, 3, 0>::new())]
+#[case(Matrix::<f64, 0, 2>::new())]
+fn qr<const R: usize, const C: usize>(#[case] matrix: Matrix<f64, R, C>) {
+    let (q, r) = matrix.qr();
+
+    assert_close(&(q * r), &matrix);
+    assert_close(&(q.to_transpose() * q), &Matrix::identity());
+    for col in 0..C {
+        for row in col + 1..R {
+            assert!(r[(row, col)].abs() < f64::EPSILON);
+        }
+    }
+}
+
+#[rstest]
+#[case(Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0], [1.0, 0.0, 1.0]]))]
+#[case(Matrix::from([[3.0], [4.0]]))]
+#[case(Matrix::<f64, 2, 2>::identity())]
+fn thin_qr<const R: usize, const C: usize>(#[case] matrix: Matrix<f64, R, C>) {
+    let (q, r) = matrix.thin_qr();
+
+    assert_close(&(q * r), &matrix);
+    assert_close(&(q.to_transpose() * q), &Matrix::identity());
+    for col in 0..C {
+        for row in col + 1..C {
+            assert!(r[(row, col)].abs() < f64::EPSILON);
+        }
+    }
+}
+
+#[rstest]
+#[case(Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0], [1.0, 0.0, 1.0]]))]
+#[case(Matrix::from([[2.0, 1.0], [1.0, 3.0]]))]
+#[case(Matrix::from([[1.0], [2.0], [2.0]]))]
+fn least_squares_full_rank<const R: usize, const C: usize>(#[case] matrix: Matrix<f64, R, C>) {
+    let b = Matrix::<f64, R, 2>::from_fn(|row, col| if (row + col) % 2 == 0 { 1.0 } else { -2.0 });
+    let x = matrix.least_squares(&b).expect("jacobi converges");
+    let normal = matrix.to_transpose() * matrix;
+
+    assert_close(&(normal * x), &(matrix.to_transpose() * b));
+}
+
+#[rstest]
+#[case(Matrix::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 2.0, 3.0], [0.0, 0.0, 0.0]]))]
+#[case(Matrix::from([[1.0, 2.0, 0.0], [0.0, 1.0, 1.0]]))]
+#[case(Matrix::<f64, 3, 2>::new())]
+fn least_squares_minimum_norm<const R: usize, const C: usize>(#[case] matrix: Matrix<f64, R, C>) {
+    let b = Matrix::<f64, R, 1>::from_fn(|row, _| if row % 2 == 0 { 1.0 } else { 3.0 });
+
+    assert_close(
+        &matrix.least_squares(&b).expect("jacobi converges"),
+        &(matrix.pseudo_inverse(1e-10).expect("jacobi converges") * b),
+    );
+}
@@ -0,0 +1,80 @@
+mod common;
+
+use common::{assert_close, to_f64};
+use matrixes::Matrix;
+use rstest::*;
+
+#[fixture]
+fn pseudo_random() -> Matrix<f64, 6, 6> {
+    Matrix::from_fn(|row, col| to_f64((row * 5 + col * 2) % 7) - 3.0)
+}
+
+#[rstest]
+#[case(pseudo_random())]
+#[case(Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]]))]
+#[case(Matrix::from([[0.0, -1.0], [1.0, 0.0]]))]
+#[case(Matrix::<f64, 4, 4>::identity())]
+#[case(Matrix::<f64, 0, 0>::new())]
+fn hessenberg<const N: usize>(#[case] matrix: Matrix<f64, N, N>) {
+    let (q, h) = matrix.hessenberg();
+
+    assert_close(&(q * h * q.to_transpose()), &matrix);
+    assert_close(&(q.to_transpose() * q), &Matrix::identity());
+
+    for row in 0..N {
+        for col in 0..row.saturating_sub(1) {
+            assert!(h[(row, col)].abs() < f64::EPSILON);
+        }
+    }
+}
+
+#[rstest]
+#[case(pseudo_random())]
+#[case(Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]]))]
+#[case(Matrix::from([[0.0, -1.0], [1.0, 0.0]]))]
+#[case(Matrix::from([[2.0, 1.0], [0.0, 2.0]]))]
+#[case(Matrix::<f64, 4, 4>::identity())]
+#[case(Matrix::<f64, 3, 3>::new())]
+#[case(Matrix::<f64, 0, 0>::new())]
+fn schur<const N: usize>(#[case] matrix: Matrix<f64, N, N>) {
+    let (q, t) = matrix.schur().expect("QR iteration converges");
+
+    assert_close(&(q * t * q.to_transpose()), &matrix);
+    assert_close(&(q.to_transpose() * q), &Matrix::identity());
+
+    for row in 1..N {
+        for col in 0..row - 1 {
+            assert!(t[(row, col)].abs() < f64::EPSILON);
+        }
+
+        let in_block = t[(row, row - 1)].abs() >= f64::EPSILON;
+        let block_above = row > 1 && t[(row - 1, row - 2)].abs() >= f64::EPSILON;
+        let block_below = row + 1 < N && t[(row + 1, row)].abs() >= f64::EPSILON;
+
+        if in_block {
+            assert!(!block_above && !block_below);
+            assert!(t[(row, row - 1)] * t[(row - 1, row)] < 0.0);
+        }
+    }
+}
+
+#[rstest]
+fn schur_eigenvalues(pseudo_random: Matrix<f64, 6, 6>) {
+    let (_, t) = pseudo_random.schur().expect("QR iteration converges");
+    let values = pseudo_random.eigenvalues().expect("QR iteration converges");
+    let mut row = 0;
+
+    while row < 6 {
+        if row + 1 < 6 && t[(row + 1, row)].abs() >= f64::EPSILON {
+            let half_trace = f64::midpoint(t[(row, row)], t[(row + 1, row + 1)]);
+
+            assert!((values[row].re - half_trace).abs() < 1e-10);
+            assert!((values[row + 1].re - half_trace).abs() < 1e-10);
+            row += 2;
+        } else {
+            assert!((values[row].re - t[(row, row)]).abs() < 1e-10);
+            assert!(values[row].im.abs() < f64::EPSILON);
+            row += 1;
+        }
+    }
+}
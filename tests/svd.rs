@@ -0,0 +1,124 @@
+mod common;
+
+use common::assert_close;
+use matrixes::{errors::SvdError, Matrix};
+use rstest::*;
+
+#[rstest]
+#[case(Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0], [1.0, 0.0, 1.0]]))]
+#[case(Matrix::from([[2.0, -1.0, 0.0, 4.0, 1.0], [0.0, 3.0, 1.0, 1.0, 2.0], [5.0, 0.0, 0.0, 1.0, 1.0]]))]
+#[case(Matrix::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 2.0, 3.0]]))]
+#[case(Matrix::from([[0.0, 1.0], [0.0, 0.0]]))]
+#[case(Matrix::<f64, 3, 3>::new())]
+#[case(Matrix::<f64, 3, 0>::new())]
+#[case(Matrix::<f64, 0, 2>::new())]
+fn svd<const R: usize, const C: usize>(#[case] matrix: Matrix<f64, R, C>) {
+    let svd = matrix.svd().expect("jacobi converges");
+
+    assert_close(&(svd.u() * svd.sigma() * svd.v_t()), &matrix);
+    assert_close(&(svd.u().to_transpose() * svd.u()), &Matrix::identity());
+    assert_close(&(svd.v_t() * svd.v_t().to_transpose()), &Matrix::identity());
+    assert_eq!(svd.singular_values().len(), R.min(C));
+    assert!(svd
+        .singular_values()
+        .windows(2)
+        .all(|pair| pair[0] >= pair[1]));
+    assert!(svd.singular_values().iter().all(|&value| value >= 0.0));
+}
+
+#[rstest]
+#[case(Matrix::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 2.0, 3.0]]), 1)]
+#[case(Matrix::from([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]), 2)]
+#[case(Matrix::<f64, 2, 4>::new(), 0)]
+fn rank_with_tol<const R: usize, const C: usize>(
+    #[case] matrix: Matrix<f64, R, C>,
+    #[case] expected: usize,
+) {
+    assert_eq!(
+        matrix.svd().expect("jacobi converges").rank_with_tol(1e-10),
+        expected
+    );
+}
+
+#[rstest]
+#[case(Matrix::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]))]
+#[case(Matrix::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]))]
+fn pseudo_inverse<const R: usize, const C: usize>(#[case] matrix: Matrix<f64, R, C>) {
+    let pinv = matrix
+        .svd()
+        .expect("jacobi converges")
+        .pseudo_inverse(1e-10);
+
+    assert_close(&(matrix * pinv * matrix), &matrix);
+    assert_close(&(pinv * matrix * pinv), &pinv);
+    assert_close(&(matrix * pinv).to_transpose(), &(matrix * pinv));
+    assert_close(&(pinv * matrix).to_transpose(), &(pinv * matrix));
+}
+
+#[rstest]
+fn pseudo_inverse_of_invertible() {
+    let matrix = Matrix::from([[4.0, 7.0], [2.0, 6.0]]);
+
+    assert_close(
+        &matrix
+            .svd()
+            .expect("jacobi converges")
+            .pseudo_inverse(1e-10),
+        &matrix.try_inverse().expect("matrix is not singular"),
+    );
+}
+
+#[rstest]
+fn condition_number() {
+    let matrix = Matrix::from([[1.0_f64, 0.0, 0.0], [0.0, 1e-3, 0.0], [0.0, 0.0, 10.0]]);
+
+    assert!((matrix.svd().expect("jacobi converges").condition_number() - 1e4).abs() < 1e-6);
+}
+
+#[rstest]
+#[case(1e200)]
+#[case(1e-200)]
+#[case(1.0)]
+fn extreme_scales(#[case] scale: f64) {
+    let golden = f64::midpoint(1.0, 5.0_f64.sqrt());
+    let matrix = Matrix::from([[scale, scale], [0.0, scale]]);
+    let values = matrix.singular_values().expect("jacobi converges");
+
+    assert!((values[0] / scale - golden).abs() < 1e-12);
+    assert!((values[1] / scale - golden.recip()).abs() < 1e-12);
+}
+
+#[rstest]
+fn single_precision() {
+    let matrix = Matrix::from([[3.0_f32, 0.0], [4.0, 5.0]]);
+    let values = matrix.singular_values().expect("jacobi converges");
+
+    assert!((values[0] - 45.0_f32.sqrt()).abs() < 1e-5);
+    assert!((values[1] - 5.0_f32.sqrt()).abs() < 1e-5);
+}
+
+#[rstest]
+#[case(Matrix::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]))]
+#[case(Matrix::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]))]
+#[case(Matrix::<f64, 2, 3>::new())]
+fn matrix_pseudo_inverse<const R: usize, const C: usize>(#[case] matrix: Matrix<f64, R, C>) {
+    assert_close(
+        &matrix.pseudo_inverse(1e-10).expect("jacobi converges"),
+        &matrix
+            .svd()
+            .expect("jacobi converges")
+            .pseudo_inverse(1e-10),
+    );
+}
+
+#[rstest]
+fn no_convergence() {
+    let matrix = Matrix::from([[1.0, f64::NAN], [2.0, 3.0]]);
+
+    assert_eq!(matrix.svd(), Err(SvdError::NoConvergence { sweeps: 64 }));
+    assert_eq!(
+        matrix.singular_values(),
+        Err(SvdError::NoConvergence { sweeps: 64 })
+    );
+    assert!(matrix.norm_2().is_nan());
+}
@@ -0,0 +1,180 @@
+mod common;
+
+use common::assert_close;
+use matrixes::{errors::IndexError, Givens, Householder, Matrix};
+use rstest::*;
+
+#[fixture]
+fn four_by_three() -> Matrix<f64, 4, 3> {
+    Matrix::from([
+        [2.0, -1.0, 0.5],
+        [1.0, 3.0, -2.0],
+        [-4.0, 0.0, 1.0],
+        [2.0, 2.0, 2.0],
+    ])
+}
+
+mod householder {
+    use super::*;
+
+    #[rstest]
+    #[case(Matrix::from([[3.0], [4.0], [0.0], [-12.0]]), 0)]
+    #[case(Matrix::from([[3.0], [-4.0], [2.0], [1.0]]), 1)]
+    #[case(Matrix::from([[3.0], [4.0], [0.0], [-12.0]]), 3)]
+    #[case(Matrix::from([[3.0], [0.0], [0.0], [0.0]]), 1)]
+    fn new_at(#[case] x: Matrix<f64, 4, 1>, #[case] start: usize) {
+        let reflection = Householder::new_at(&x, start).expect("start is within bounds");
+        let h = reflection.to_matrix();
+        let norm = (start..4).fold(0.0_f64, |norm, row| norm.hypot(x[(row, 0)]));
+        let mut reflected = x;
+        reflection.apply_left(&mut reflected);
+
+        assert_close(&(h * h), &Matrix::identity());
+        assert_close(&h.to_transpose(), &h);
+        assert_close(&(h * x), &reflected);
+        assert!((reflection.alpha().abs() - norm).abs() < 1e-12);
+
+        for row in 0..4 {
+            let expected = match row {
+                _ if row < start => x[(row, 0)],
+                _ if row == start => reflection.alpha(),
+                _ => 0.0,
+            };
+
+            assert!((reflected[(row, 0)] - expected).abs() < 1e-12);
+        }
+    }
+
+    #[rstest]
+    fn new_at_out_of_bounds() {
+        assert_eq!(
+            Householder::new_at(&Matrix::from([[1.0], [2.0]]), 2),
+            Err(IndexError::Row(2))
+        );
+    }
+
+    #[rstest]
+    fn apply(four_by_three: Matrix<f64, 4, 3>) {
+        let reflection = Householder::new(&Matrix::from([[1.0], [2.0], [-2.0], [0.5]]));
+        let h = reflection.to_matrix();
+
+        let mut left = four_by_three;
+        reflection.apply_left(&mut left);
+        assert_close(&left, &(h * four_by_three));
+
+        let mut right = four_by_three.to_transpose();
+        reflection.apply_right(&mut right);
+        assert_close(&right, &(four_by_three.to_transpose() * h));
+    }
+
+    #[rstest]
+    #[case(1e-200)]
+    #[case(1e200)]
+    fn extreme_magnitudes(#[case] scale: f64) {
+        let mut x = Matrix::from([[scale], [scale]]);
+        let reflection = Householder::new(&x);
+        reflection.apply_left(&mut x);
+
+        assert!((x[(0, 0)] / scale + 2.0_f64.sqrt()).abs() < 1e-12);
+        assert!((x[(1, 0)] / scale).abs() < 1e-12);
+        assert_close(
+            &(reflection.to_matrix() * reflection.to_matrix()),
+            &Matrix::identity(),
+        );
+    }
+
+    #[rstest]
+    fn zero_vector() {
+        let reflection = Householder::new(&Matrix::<f64, 3, 1>::new());
+
+        assert_eq!(reflection.to_matrix(), Matrix::identity());
+        assert!(reflection.alpha().abs() < f64::EPSILON);
+    }
+}
+
+mod givens {
+    use super::*;
+
+    #[rstest]
+    #[case(3.0, 4.0)]
+    #[case(-1.0, 1e-3)]
+    #[case(0.0, -2.0)]
+    #[case(0.0, 0.0)]
+    fn new(#[case] a: f64, #[case] b: f64) {
+        let rotation = Givens::new(a, b);
+
+        assert!((rotation.c().hypot(rotation.s()) - 1.0).abs() < 1e-12);
+        let mut vector = Matrix::from([[a], [b]]);
+        rotation
+            .apply_to_rows(&mut vector, 0, 1)
+            .expect("rows are within bounds");
+
+        assert_close(&vector, &Matrix::from([[rotation.r()], [0.0]]));
+    }
+
+    #[rstest]
+    fn apply_to_rows(four_by_three: Matrix<f64, 4, 3>) {
+        let rotation = Givens::new(four_by_three[(1, 0)], four_by_three[(3, 0)]);
+        let mut rotated = four_by_three;
+        rotation
+            .apply_to_rows(&mut rotated, 1, 3)
+            .expect("rows are within bounds");
+
+        assert!((rotated[(1, 0)] - rotation.r()).abs() < 1e-12);
+        assert!(rotated[(3, 0)].abs() < 1e-12);
+        assert_close(
+            &(rotated.to_transpose() * rotated),
+            &(four_by_three.to_transpose() * four_by_three),
+        );
+
+        assert_eq!(rotated.get_row(0), four_by_three.get_row(0));
+        assert_eq!(rotated.get_row(2), four_by_three.get_row(2));
+    }
+
+    #[rstest]
+    fn apply_to_cols(four_by_three: Matrix<f64, 4, 3>) {
+        let rotation = Givens::new(four_by_three[(2, 0)], four_by_three[(2, 2)]);
+        let mut rotated = four_by_three;
+        rotation
+            .apply_to_cols(&mut rotated, 0, 2)
+            .expect("columns are within bounds");
+
+        let mut transposed = four_by_three.to_transpose();
+        rotation
+            .apply_to_rows(&mut transposed, 0, 2)
+            .expect("rows are within bounds");
+
+        assert!(rotated[(2, 2)].abs() < 1e-12);
+        assert_close(&rotated, &transposed.to_transpose());
+    }
+
+    #[rstest]
+    fn out_of_bounds(four_by_three: Matrix<f64, 4, 3>) {
+        let rotation = Givens::new(1.0, 1.0);
+        let mut matrix = four_by_three;
+
+        assert_eq!(
+            rotation.apply_to_rows(&mut matrix, 0, 4),
+            Err(IndexError::Row(4))
+        );
+        assert_eq!(
+            rotation.apply_to_cols(&mut matrix, 3, 0),
+            Err(IndexError::Column(3))
+        );
+        assert_eq!(matrix, four_by_three);
+    }
+
+    #[rstest]
+    fn same_index(four_by_three: Matrix<f64, 4, 3>) {
+        let rotation = Givens::new(3.0, 4.0);
+        let mut matrix = four_by_three;
+
+        assert_eq!(rotation.apply_to_rows(&mut matrix, 2, 2), Ok(()));
+        assert_eq!(rotation.apply_to_cols(&mut matrix, 0, 0), Ok(()));
+        assert_eq!(matrix, four_by_three);
+
+        let mut row = Matrix::from([[3.0, 4.0]]);
+        assert_eq!(rotation.apply_to_cols(&mut row, 0, 0), Ok(()));
+        assert_eq!(row, Matrix::from([[3.0, 4.0]]));
+    }
+}